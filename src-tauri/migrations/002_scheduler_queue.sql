@@ -0,0 +1,14 @@
+-- 调度队列表（延迟、循环、超时检测），应用重启后据此恢复内存队列
+CREATE TABLE IF NOT EXISTS scheduler_queue (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    queue_type TEXT NOT NULL,
+    reminder_uuid TEXT NOT NULL,
+    due_at TEXT NOT NULL,
+    original_triggered_at TEXT NOT NULL,
+    timeout_minutes INTEGER,
+    created_at TEXT NOT NULL,
+    UNIQUE (queue_type, reminder_uuid),
+    FOREIGN KEY (reminder_uuid) REFERENCES reminders(uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_scheduler_queue_due_at ON scheduler_queue(due_at);
@@ -0,0 +1,2 @@
+-- 单次提醒的指定日期（YYYY-MM-DD），为空时在下一个 remind_time 触发
+ALTER TABLE reminders ADD COLUMN remind_date TEXT;
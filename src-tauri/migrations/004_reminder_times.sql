@@ -0,0 +1,2 @@
+-- 每天多个触发时间点（JSON 数组，如 ["10:00","14:00","16:30"]），为空时只使用 remind_time
+ALTER TABLE reminders ADD COLUMN remind_times TEXT;
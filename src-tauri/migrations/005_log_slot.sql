@@ -0,0 +1,2 @@
+-- 触发日志对应的时间点（多时间点提醒用于区分是哪一次触发）
+ALTER TABLE reminder_logs ADD COLUMN slot TEXT;
@@ -0,0 +1,2 @@
+-- 高级重复规则（cron 表达式或 iCalendar RRULE），remind_type 为 advanced 时使用
+ALTER TABLE reminders ADD COLUMN recurrence_rule TEXT;
@@ -0,0 +1,6 @@
+-- 间隔重复：每 N 分钟/小时/天，从锚点时间开始计算，可限定每天的生效时段
+ALTER TABLE reminders ADD COLUMN interval_value INTEGER;
+ALTER TABLE reminders ADD COLUMN interval_unit TEXT;
+ALTER TABLE reminders ADD COLUMN anchor_at TEXT;
+ALTER TABLE reminders ADD COLUMN window_start TEXT;
+ALTER TABLE reminders ADD COLUMN window_end TEXT;
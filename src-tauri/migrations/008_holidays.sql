@@ -0,0 +1,9 @@
+-- 节假日与调休日历（内置数据每次启动时刷新，用户导入的数据优先）
+CREATE TABLE IF NOT EXISTS holidays (
+    date TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    name TEXT NOT NULL DEFAULT '',
+    source TEXT NOT NULL DEFAULT 'bundled',
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (date, source)
+);
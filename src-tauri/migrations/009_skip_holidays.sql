@@ -0,0 +1,2 @@
+-- 法定节假日跳过提醒
+ALTER TABLE reminders ADD COLUMN skip_holidays INTEGER NOT NULL DEFAULT 0;
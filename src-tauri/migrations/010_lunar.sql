@@ -0,0 +1,5 @@
+-- 农历重复：农历月（为空表示每月）、农历日、闰月处理方式、提前天数
+ALTER TABLE reminders ADD COLUMN lunar_month INTEGER;
+ALTER TABLE reminders ADD COLUMN lunar_day INTEGER;
+ALTER TABLE reminders ADD COLUMN lunar_leap_policy TEXT;
+ALTER TABLE reminders ADD COLUMN lunar_days_before INTEGER;
@@ -0,0 +1,4 @@
+-- 每年重复：月、日，以及当年没有该日期（如 2 月 29 日）时的处理方式
+ALTER TABLE reminders ADD COLUMN yearly_month INTEGER;
+ALTER TABLE reminders ADD COLUMN yearly_day INTEGER;
+ALTER TABLE reminders ADD COLUMN leap_day_policy TEXT;
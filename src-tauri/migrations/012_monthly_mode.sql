@@ -0,0 +1,5 @@
+-- 每月重复方式：按日期、第 N 个星期几、最后一个星期几、最后一天、月末前 N 天
+ALTER TABLE reminders ADD COLUMN monthly_mode TEXT;
+ALTER TABLE reminders ADD COLUMN month_week INTEGER;
+ALTER TABLE reminders ADD COLUMN month_weekday INTEGER;
+ALTER TABLE reminders ADD COLUMN days_before_end INTEGER;
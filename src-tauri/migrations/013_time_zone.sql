@@ -0,0 +1,3 @@
+-- 提醒时区（IANA 名称，为空时使用本机时区）及夏令时跳变的处理方式
+ALTER TABLE reminders ADD COLUMN time_zone TEXT;
+ALTER TABLE reminders ADD COLUMN dst_policy TEXT;
@@ -0,0 +1,4 @@
+-- 重复提醒的生效日期范围和最大触发次数，到期后自动禁用
+ALTER TABLE reminders ADD COLUMN starts_on TEXT;
+ALTER TABLE reminders ADD COLUMN ends_on TEXT;
+ALTER TABLE reminders ADD COLUMN max_occurrences INTEGER;
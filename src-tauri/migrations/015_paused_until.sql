@@ -0,0 +1,2 @@
+-- 暂停到指定时间（跳过下一次提醒即暂停到下一次触发时间），之后自动恢复
+ALTER TABLE reminders ADD COLUMN paused_until TEXT;
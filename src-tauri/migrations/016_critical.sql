@@ -0,0 +1,2 @@
+-- 重要提醒：勿扰期间照常弹窗
+ALTER TABLE reminders ADD COLUMN is_critical INTEGER NOT NULL DEFAULT 0;
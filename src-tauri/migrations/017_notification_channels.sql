@@ -0,0 +1,17 @@
+-- 通知渠道
+CREATE TABLE IF NOT EXISTS notification_channels (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    config TEXT NOT NULL DEFAULT '{}',
+    is_enabled INTEGER NOT NULL DEFAULT 1,
+    allow_during_dnd INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- 提醒各事件发送到的渠道（渠道 uuid 的 JSON 数组）
+ALTER TABLE reminders ADD COLUMN trigger_channels TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE reminders ADD COLUMN complete_channels TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE reminders ADD COLUMN timeout_channels TEXT NOT NULL DEFAULT '[]';
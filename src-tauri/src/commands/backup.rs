@@ -0,0 +1,19 @@
+use tauri::AppHandle;
+use crate::error::AppResult;
+use crate::models::backup::BackupInfo;
+use crate::services::backup_service;
+
+#[tauri::command]
+pub async fn list_backups(app: AppHandle) -> AppResult<Vec<BackupInfo>> {
+    backup_service::list_backups(&app)
+}
+
+#[tauri::command]
+pub async fn create_backup(app: AppHandle) -> AppResult<BackupInfo> {
+    backup_service::create_backup(&app)
+}
+
+#[tauri::command]
+pub async fn restore_backup(app: AppHandle, file_name: String) -> AppResult<()> {
+    backup_service::restore_backup(&app, &file_name).await
+}
@@ -0,0 +1,54 @@
+use tauri::AppHandle;
+use crate::error::AppResult;
+use crate::models::channel::{Channel, ChannelKind, CreateChannelRequest, UpdateChannelRequest};
+use crate::services::channel_service;
+
+#[tauri::command]
+pub async fn get_notification_channels(app: AppHandle) -> AppResult<Vec<Channel>> {
+    channel_service::get_channels(&app)
+}
+
+#[tauri::command]
+pub async fn create_notification_channel(app: AppHandle, request: CreateChannelRequest) -> AppResult<Channel> {
+    channel_service::create_channel(&app, request)
+}
+
+#[tauri::command]
+pub async fn update_notification_channel(app: AppHandle, request: UpdateChannelRequest) -> AppResult<Channel> {
+    channel_service::update_channel(&app, request)
+}
+
+#[tauri::command]
+pub async fn delete_notification_channel(app: AppHandle, uuid: String) -> AppResult<()> {
+    channel_service::delete_channel(&app, &uuid)
+}
+
+#[tauri::command]
+pub async fn test_notification_channel(app: AppHandle, uuid: String) -> AppResult<()> {
+    channel_service::test_channel(&app, &uuid).await
+}
+
+#[tauri::command]
+pub async fn test_telegram(app: AppHandle, config: serde_json::Value) -> AppResult<bool> {
+    channel_service::test_config(&app, ChannelKind::Telegram, &config).await
+}
+
+#[tauri::command]
+pub async fn test_bark(app: AppHandle, config: serde_json::Value) -> AppResult<bool> {
+    channel_service::test_config(&app, ChannelKind::Bark, &config).await
+}
+
+#[tauri::command]
+pub async fn test_ntfy(app: AppHandle, config: serde_json::Value) -> AppResult<bool> {
+    channel_service::test_config(&app, ChannelKind::Ntfy, &config).await
+}
+
+#[tauri::command]
+pub async fn test_gotify(app: AppHandle, config: serde_json::Value) -> AppResult<bool> {
+    channel_service::test_config(&app, ChannelKind::Gotify, &config).await
+}
+
+#[tauri::command]
+pub async fn test_pushplus(app: AppHandle, config: serde_json::Value) -> AppResult<bool> {
+    channel_service::test_config(&app, ChannelKind::PushPlus, &config).await
+}
@@ -0,0 +1,9 @@
+use tauri::AppHandle;
+use crate::error::AppResult;
+use crate::models::digest::Digest;
+use crate::services::digest_service;
+
+#[tauri::command]
+pub async fn send_digest(app: AppHandle) -> AppResult<Digest> {
+    digest_service::send_digest(&app).await
+}
@@ -0,0 +1,19 @@
+use tauri::AppHandle;
+use crate::error::AppResult;
+use crate::models::holiday::HolidayDay;
+use crate::services::holiday_service;
+
+#[tauri::command]
+pub async fn get_holidays(app: AppHandle, year: Option<i32>) -> AppResult<Vec<HolidayDay>> {
+    holiday_service::get_holidays(&app, year)
+}
+
+#[tauri::command]
+pub async fn import_holiday_calendar(app: AppHandle, path: String) -> AppResult<usize> {
+    holiday_service::import_calendar(&app, &path)
+}
+
+#[tauri::command]
+pub async fn reset_holiday_calendar(app: AppHandle) -> AppResult<()> {
+    holiday_service::reset_calendar(&app)
+}
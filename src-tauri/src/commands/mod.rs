@@ -1,2 +1,6 @@
 pub mod reminder;
 pub mod settings;
+pub mod holiday;
+pub mod backup;
+pub mod channel;
+pub mod digest;
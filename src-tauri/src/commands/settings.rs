@@ -1,36 +1,53 @@
 use tauri::AppHandle;
-use crate::models::reminder::Reminder;
-use crate::models::settings::AppSettings;
-use crate::services::settings_service;
+use crate::error::AppResult;
+use crate::models::channel::ChannelKind;
+use crate::models::reminder::{Reminder, UpcomingReminder};
+use crate::models::settings::{AppSettings, SmtpSettings};
+use crate::services::{channel_service, settings_service};
 
 #[tauri::command]
-pub async fn get_settings(app: AppHandle) -> Result<AppSettings, String> {
-    settings_service::get_settings(&app).map_err(|e| e.to_string())
+pub async fn get_settings(app: AppHandle) -> AppResult<AppSettings> {
+    settings_service::get_settings(&app)
 }
 
 #[tauri::command]
-pub async fn update_settings(app: AppHandle, settings: AppSettings) -> Result<(), String> {
-    settings_service::update_settings(&app, settings).map_err(|e| e.to_string())
+pub async fn update_settings(app: AppHandle, settings: AppSettings) -> AppResult<()> {
+    settings_service::update_settings(&app, settings)
 }
 
+/// 旧版设置页的 Server酱 测试，按 Server酱 渠道配置发送测试消息
 #[tauri::command]
-pub async fn test_serverchan(domain: Option<String>, sendkey: String) -> Result<bool, String> {
-    crate::notification::serverchan::test_connection(domain.as_deref(), &sendkey)
-        .await
-        .map_err(|e| e.to_string())
+pub async fn test_serverchan(app: AppHandle, domain: Option<String>, sendkey: String) -> AppResult<bool> {
+    let config = serde_json::json!({ "domain": domain, "sendkey": sendkey });
+    channel_service::test_config(&app, ChannelKind::ServerChan, &config).await
 }
 
 #[tauri::command]
-pub async fn set_auto_start(app: AppHandle, enabled: bool) -> Result<(), String> {
-    settings_service::set_auto_start(&app, enabled).map_err(|e| e.to_string())
+pub async fn set_auto_start(app: AppHandle, enabled: bool) -> AppResult<()> {
+    settings_service::set_auto_start(&app, enabled)
 }
 
 #[tauri::command]
-pub async fn get_auto_start(app: AppHandle) -> Result<bool, String> {
-    settings_service::get_auto_start(&app).map_err(|e| e.to_string())
+pub async fn get_auto_start(app: AppHandle) -> AppResult<bool> {
+    settings_service::get_auto_start(&app)
 }
 
 #[tauri::command]
-pub async fn get_next_reminder(app: AppHandle) -> Result<Option<Reminder>, String> {
-    settings_service::get_next_reminder(&app).map_err(|e| e.to_string())
+pub async fn set_dnd(app: AppHandle, enabled: bool) -> AppResult<()> {
+    settings_service::set_dnd(&app, enabled)
+}
+
+#[tauri::command]
+pub async fn get_next_reminder(app: AppHandle) -> AppResult<Option<Reminder>> {
+    settings_service::get_next_reminder(&app).await
+}
+
+#[tauri::command]
+pub async fn get_upcoming_reminders(app: AppHandle, limit: Option<usize>) -> AppResult<Vec<UpcomingReminder>> {
+    settings_service::get_upcoming_reminders(&app, limit.unwrap_or(10)).await
+}
+
+#[tauri::command]
+pub async fn test_smtp(app: AppHandle, smtp: SmtpSettings, to: Option<String>) -> AppResult<bool> {
+    settings_service::test_smtp(&app, &smtp, to.as_deref()).await
 }
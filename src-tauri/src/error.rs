@@ -0,0 +1,100 @@
+use rusqlite::ErrorCode;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use thiserror::Error;
+
+/// 应用统一错误类型
+///
+/// 返回给前端时序列化为 `{ code, message, details }`：`code` 供前端区分错误类型，
+/// `message` 可直接展示给用户，`details` 为原始错误信息（便于排查，可能为空）。
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("数据库操作失败")]
+    Storage(#[from] rusqlite::Error),
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("{entity}不存在")]
+    NotFound { entity: &'static str, id: String },
+
+    #[error("通知发送失败: {0}")]
+    Notification(String),
+
+    #[error("调度器错误: {0}")]
+    Scheduler(String),
+
+    #[error("文件读写失败")]
+    Io(#[from] std::io::Error),
+
+    #[error("数据格式无效")]
+    Data(#[from] serde_json::Error),
+
+    #[error("系统操作失败: {0}")]
+    Platform(String),
+
+    #[error("数据库版本 {found} 高于当前应用支持的版本 {supported}，请升级应用")]
+    SchemaTooNew { found: u32, supported: u32 },
+}
+
+pub type AppResult<T> = Result<T, AppError>;
+
+impl AppError {
+    /// 提醒不存在
+    pub fn reminder_not_found(uuid: &str) -> Self {
+        Self::NotFound { entity: "提醒", id: uuid.to_string() }
+    }
+
+    /// 错误代码（前端据此区分错误类型）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Storage(rusqlite::Error::QueryReturnedNoRows) => "not_found",
+            Self::Storage(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(e.code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked) =>
+            {
+                "database_locked"
+            }
+            Self::Storage(_) => "storage",
+            Self::Validation(_) => "validation",
+            Self::NotFound { .. } => "not_found",
+            Self::Notification(_) => "notification",
+            Self::Scheduler(_) => "scheduler",
+            Self::Io(_) => "io",
+            Self::Data(_) => "invalid_data",
+            Self::Platform(_) => "platform",
+            Self::SchemaTooNew { .. } => "schema_too_new",
+        }
+    }
+
+    /// 原始错误信息
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::Storage(e) => Some(e.to_string()),
+            Self::NotFound { id, .. } => Some(id.clone()),
+            Self::Io(e) => Some(e.to_string()),
+            Self::Data(e) => Some(e.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(e: tauri::Error) -> Self {
+        Self::Platform(e.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Notification(e.to_string())
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
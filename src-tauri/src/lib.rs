@@ -1,4 +1,5 @@
 pub mod commands;
+pub mod error;
 pub mod models;
 pub mod services;
 pub mod scheduler;
@@ -9,7 +10,7 @@ pub mod utils;
 use tauri::{
     Manager,
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    menu::{Menu, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem},
     WindowEvent,
 };
 use tauri_plugin_autostart::MacosLauncher;
@@ -31,15 +32,23 @@ pub fn run() {
             storage::database::init_database(&app_handle)?;
 
             // 启动调度器
-            let app_handle_clone = app.handle().clone();
-            tauri::async_runtime::spawn(async move {
-                scheduler::manager::start_scheduler(app_handle_clone).await;
-            });
+            scheduler::manager::spawn_scheduler(app.handle().clone());
+
+            // 定时自动备份
+            tauri::async_runtime::spawn(services::backup_service::run_auto_backup(app.handle().clone()));
+
+            // 定时发送摘要邮件
+            tauri::async_runtime::spawn(services::digest_service::run_digest(app.handle().clone()));
 
             // 创建托盘菜单
             let show_item = MenuItem::with_id(app, "show", "显示窗口", true, None::<&str>)?;
+            let dnd_enabled = storage::settings_repo::get_all()
+                .map(|s| s.dnd_enabled)
+                .unwrap_or(false);
+            let dnd_item = CheckMenuItem::with_id(app, "dnd", "勿扰模式", true, dnd_enabled, None::<&str>)?;
             let quit_item = MenuItem::with_id(app, "quit", "退出", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show_item, &quit_item])?;
+            let menu = Menu::with_items(app, &[&show_item, &dnd_item, &quit_item])?;
+            app.manage(notification::tray::DndMenuItem(dnd_item));
 
             // 创建系统托盘（带 ID）
             let _tray = TrayIconBuilder::with_id(TRAY_ID)
@@ -54,6 +63,14 @@ pub fn run() {
                                 let _ = window.set_focus();
                             }
                         }
+                        "dnd" => {
+                            let enabled = storage::settings_repo::get_all()
+                                .map(|s| !s.dnd_enabled)
+                                .unwrap_or(true);
+                            if let Err(e) = services::settings_service::set_dnd(app, enabled) {
+                                eprintln!("切换勿扰模式失败: {}", e);
+                            }
+                        }
                         "quit" => {
                             app.exit(0);
                         }
@@ -104,18 +121,46 @@ pub fn run() {
             commands::reminder::update_reminder,
             commands::reminder::delete_reminder,
             commands::reminder::toggle_reminder,
+            commands::reminder::skip_next_occurrence,
+            commands::reminder::pause_reminder,
+            commands::reminder::resume_reminder,
             commands::reminder::reorder_reminders,
             commands::reminder::complete_reminder,
             commands::reminder::snooze_reminder,
             commands::reminder::dismiss_reminder,
             commands::reminder::get_reminder_logs,
+            commands::reminder::get_reminder_occurrences,
             // 设置相关
             commands::settings::get_settings,
             commands::settings::update_settings,
             commands::settings::test_serverchan,
             commands::settings::set_auto_start,
             commands::settings::get_auto_start,
+            commands::settings::set_dnd,
             commands::settings::get_next_reminder,
+            commands::settings::get_upcoming_reminders,
+            commands::settings::test_smtp,
+            // 节假日日历
+            commands::holiday::get_holidays,
+            commands::holiday::import_holiday_calendar,
+            commands::holiday::reset_holiday_calendar,
+            // 备份与恢复
+            commands::backup::list_backups,
+            commands::backup::create_backup,
+            commands::backup::restore_backup,
+            // 通知渠道
+            commands::channel::get_notification_channels,
+            commands::channel::create_notification_channel,
+            commands::channel::update_notification_channel,
+            commands::channel::delete_notification_channel,
+            commands::channel::test_notification_channel,
+            commands::channel::test_telegram,
+            commands::channel::test_bark,
+            commands::channel::test_ntfy,
+            commands::channel::test_gotify,
+            commands::channel::test_pushplus,
+            // 摘要邮件
+            commands::digest::send_digest,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
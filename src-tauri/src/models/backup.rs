@@ -0,0 +1,54 @@
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+
+/// 备份类型（由文件名前缀区分：notice-<类型>-<时间>.db）
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    /// 定时自动备份（超过保留数量时删除最旧的）
+    Auto,
+    /// 手动备份
+    Manual,
+    /// 升级数据库前的备份
+    Migration,
+    /// 恢复备份前对当前数据的备份
+    PreRestore,
+}
+
+impl BackupKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Manual => "manual",
+            Self::Migration => "migration",
+            Self::PreRestore => "pre_restore",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "auto" => Self::Auto,
+            "migration" => Self::Migration,
+            "pre_restore" => Self::PreRestore,
+            _ => Self::Manual,
+        }
+    }
+
+    /// 从备份文件名解析类型
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let rest = file_name.strip_prefix("notice-")?.strip_suffix(".db")?;
+        let (kind, _) = rest.split_once('-')?;
+        Some(Self::from_str(kind))
+    }
+}
+
+/// 备份文件信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub file_name: String,
+    pub kind: BackupKind,
+    /// 文件大小（字节）
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// 通知渠道类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelKind {
+    /// Server酱
+    ServerChan,
+    /// 自定义 Webhook
+    Webhook,
+    /// 钉钉群机器人
+    DingTalk,
+    /// 企业微信群机器人
+    WeCom,
+    /// 飞书群机器人
+    Feishu,
+    Telegram,
+    /// Bark（iOS）
+    Bark,
+    Ntfy,
+    Gotify,
+    PushPlus,
+    /// 邮件
+    Email,
+}
+
+impl ChannelKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ServerChan => "serverchan",
+            Self::Webhook => "webhook",
+            Self::DingTalk => "dingtalk",
+            Self::WeCom => "wecom",
+            Self::Feishu => "feishu",
+            Self::Telegram => "telegram",
+            Self::Bark => "bark",
+            Self::Ntfy => "ntfy",
+            Self::Gotify => "gotify",
+            Self::PushPlus => "pushplus",
+            Self::Email => "email",
+        }
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::ServerChan => "Server酱",
+            Self::Webhook => "Webhook",
+            Self::DingTalk => "钉钉",
+            Self::WeCom => "企业微信",
+            Self::Feishu => "飞书",
+            Self::Telegram => "Telegram",
+            Self::Bark => "Bark",
+            Self::Ntfy => "ntfy",
+            Self::Gotify => "Gotify",
+            Self::PushPlus => "PushPlus",
+            Self::Email => "邮件",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "serverchan" => Self::ServerChan,
+            "webhook" => Self::Webhook,
+            "dingtalk" => Self::DingTalk,
+            "wecom" => Self::WeCom,
+            "feishu" => Self::Feishu,
+            "telegram" => Self::Telegram,
+            "bark" => Self::Bark,
+            "ntfy" => Self::Ntfy,
+            "gotify" => Self::Gotify,
+            "pushplus" => Self::PushPlus,
+            "email" => Self::Email,
+            _ => Self::ServerChan,
+        }
+    }
+}
+
+/// 已配置的通知渠道
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    pub id: Option<i64>,
+    pub uuid: String,
+    pub name: String,
+    pub kind: ChannelKind,
+    /// 渠道配置（格式由渠道类型决定，如 Server酱的 `{ domain, sendkey }`、Webhook 的 `{ url, method, headers, bodyTemplate }`、邮件的 `{ to }`）
+    pub config: serde_json::Value,
+    pub is_enabled: bool,
+    /// 勿扰期间是否仍发送
+    pub allow_during_dnd: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 创建通知渠道请求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChannelRequest {
+    pub name: String,
+    pub kind: ChannelKind,
+    pub config: serde_json::Value,
+    pub is_enabled: Option<bool>,
+    pub allow_during_dnd: Option<bool>,
+}
+
+/// 更新通知渠道请求（渠道类型创建后不可修改）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChannelRequest {
+    pub uuid: String,
+    pub name: Option<String>,
+    pub config: Option<serde_json::Value>,
+    pub is_enabled: Option<bool>,
+    pub allow_during_dnd: Option<bool>,
+}
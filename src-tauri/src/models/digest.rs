@@ -0,0 +1,25 @@
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+
+/// 摘要中单个提醒的统计
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestEntry {
+    pub reminder_uuid: String,
+    pub title: String,
+    /// 触发次数（不含重复提醒）
+    pub triggered: i64,
+    pub completed: i64,
+    pub dismissed: i64,
+    pub timed_out: i64,
+    pub missed: i64,
+}
+
+/// 一段时间内的提醒记录摘要
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Digest {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub entries: Vec<DigestEntry>,
+}
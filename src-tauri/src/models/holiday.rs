@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+
+/// 节假日类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HolidayKind {
+    /// 法定节假日（休息）
+    Holiday,
+    /// 调休上班日
+    Workday,
+}
+
+impl HolidayKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Holiday => "holiday",
+            Self::Workday => "workday",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "workday" => Self::Workday,
+            _ => Self::Holiday,
+        }
+    }
+}
+
+/// 节假日日历中的一天
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayDay {
+    pub date: NaiveDate,
+    pub kind: HolidayKind,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// 节假日日历文件（内置数据与用户导入使用相同格式）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayCalendar {
+    #[serde(default)]
+    pub version: String,
+    pub days: Vec<HolidayDay>,
+}
+
+/// 日历数据来源
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HolidaySource {
+    Bundled,
+    User,
+}
+
+impl HolidaySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bundled => "bundled",
+            Self::User => "user",
+        }
+    }
+}
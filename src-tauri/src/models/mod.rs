@@ -1,3 +1,8 @@
 pub mod reminder;
 pub mod settings;
 pub mod reminder_log;
+pub mod queue_item;
+pub mod holiday;
+pub mod backup;
+pub mod channel;
+pub mod digest;
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// 调度队列类型
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueType {
+    Snooze,
+    Loop,
+    Timeout,
+    /// 勿扰期间暂缓弹窗的提醒
+    Deferred,
+}
+
+impl QueueType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Snooze => "snooze",
+            Self::Loop => "loop",
+            Self::Timeout => "timeout",
+            Self::Deferred => "deferred",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "loop" => Self::Loop,
+            "timeout" => Self::Timeout,
+            "deferred" => Self::Deferred,
+            _ => Self::Snooze,
+        }
+    }
+}
+
+/// 持久化的调度队列项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueItem {
+    pub queue_type: QueueType,
+    pub reminder_uuid: String,
+    /// 到期时间（延迟到期 / 下次循环 / 超时截止 / 暂缓时的触发时间）
+    pub due_at: DateTime<Utc>,
+    pub original_triggered_at: DateTime<Utc>,
+    /// 仅超时队列使用
+    pub timeout_minutes: Option<i32>,
+}
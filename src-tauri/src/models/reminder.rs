@@ -1,5 +1,14 @@
-use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+/// 反序列化可清除的字段：缺失为 None（不修改），null 为 Some(None)（清除）
+fn nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
 
 /// 提醒类型
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -9,6 +18,16 @@ pub enum RemindType {
     Once,
     Weekday,
     Monthly,
+    /// 每年的指定月、日
+    Yearly,
+    /// 高级规则：cron 表达式或 iCalendar RRULE
+    Advanced,
+    /// 间隔重复：从锚点时间开始每 N 分钟/小时/天
+    Interval,
+    /// 中国工作日：周一至周五，按节假日日历排除法定假日并包含调休上班日
+    ChinaWorkday,
+    /// 农历重复：每年（或每月）的农历日期
+    Lunar,
 }
 
 impl Default for RemindType {
@@ -24,6 +43,11 @@ impl RemindType {
             Self::Once => "once",
             Self::Weekday => "weekday",
             Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+            Self::Advanced => "advanced",
+            Self::Interval => "interval",
+            Self::ChinaWorkday => "china_workday",
+            Self::Lunar => "lunar",
         }
     }
 
@@ -32,11 +56,197 @@ impl RemindType {
             "once" => Self::Once,
             "weekday" => Self::Weekday,
             "monthly" => Self::Monthly,
+            "yearly" => Self::Yearly,
+            "advanced" => Self::Advanced,
+            "interval" => Self::Interval,
+            "china_workday" => Self::ChinaWorkday,
+            "lunar" => Self::Lunar,
             _ => Self::Daily,
         }
     }
 }
 
+/// 间隔重复的单位
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalUnit {
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl Default for IntervalUnit {
+    fn default() -> Self {
+        Self::Minutes
+    }
+}
+
+impl IntervalUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "hours" => Self::Hours,
+            "days" => Self::Days,
+            _ => Self::Minutes,
+        }
+    }
+}
+
+/// 夏令时跳变时的处理方式
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DstPolicy {
+    /// 时间不存在（夏令时开始）时按跳变前的偏移顺延，例如 02:30 变为 03:30
+    Shift,
+    /// 时间不存在时跳过当次提醒
+    Skip,
+}
+
+impl Default for DstPolicy {
+    fn default() -> Self {
+        Self::Shift
+    }
+}
+
+impl DstPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Shift => "shift",
+            Self::Skip => "skip",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "skip" => Self::Skip,
+            _ => Self::Shift,
+        }
+    }
+}
+
+/// 每月重复的方式
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MonthlyMode {
+    /// 按 monthdays 中的日期（超过当月天数时在最后一天触发）
+    Days,
+    /// 第 N 个星期几，例如每月第二个星期二
+    NthWeekday,
+    /// 最后一个星期几，例如每月最后一个星期五
+    LastWeekday,
+    /// 每月最后一天
+    LastDay,
+    /// 月末前 N 天
+    DaysBeforeEnd,
+}
+
+impl Default for MonthlyMode {
+    fn default() -> Self {
+        Self::Days
+    }
+}
+
+impl MonthlyMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Days => "days",
+            Self::NthWeekday => "nth_weekday",
+            Self::LastWeekday => "last_weekday",
+            Self::LastDay => "last_day",
+            Self::DaysBeforeEnd => "days_before_end",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "nth_weekday" => Self::NthWeekday,
+            "last_weekday" => Self::LastWeekday,
+            "last_day" => Self::LastDay,
+            "days_before_end" => Self::DaysBeforeEnd,
+            _ => Self::Days,
+        }
+    }
+}
+
+/// 当年没有设置的日期时（如平年的 2 月 29 日）的处理方式
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeapDayPolicy {
+    /// 在当月最后一天触发（与每月提醒一致）
+    LastDay,
+    /// 在下个月第一天触发
+    NextDay,
+    /// 当年不触发
+    Skip,
+}
+
+impl Default for LeapDayPolicy {
+    fn default() -> Self {
+        Self::LastDay
+    }
+}
+
+impl LeapDayPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LastDay => "last_day",
+            Self::NextDay => "next_day",
+            Self::Skip => "skip",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "next_day" => Self::NextDay,
+            "skip" => Self::Skip,
+            _ => Self::LastDay,
+        }
+    }
+}
+
+/// 农历闰月的处理方式
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LunarLeapPolicy {
+    /// 只在非闰月触发
+    Regular,
+    /// 当年有对应闰月时在闰月触发，否则在非闰月触发（适用于闰月出生的生日）
+    PreferLeap,
+    /// 非闰月和闰月都触发
+    Both,
+}
+
+impl Default for LunarLeapPolicy {
+    fn default() -> Self {
+        Self::Regular
+    }
+}
+
+impl LunarLeapPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Regular => "regular",
+            Self::PreferLeap => "prefer_leap",
+            Self::Both => "both",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "prefer_leap" => Self::PreferLeap,
+            "both" => Self::Both,
+            _ => Self::Regular,
+        }
+    }
+}
+
 /// 同步状态
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -103,12 +313,60 @@ pub struct Reminder {
 
     // 时间设置
     pub remind_time: String,
+    /// 每天多个触发时间点（为空时只使用 remind_time）
+    pub remind_times: Option<Vec<String>>,
     pub remind_type: RemindType,
+    /// IANA 时区名称（如 "America/New_York"），为空时使用本机时区
+    pub time_zone: Option<String>,
+    /// 夏令时跳变的处理方式（重复的时间总是取较早的一次）
+    pub dst_policy: Option<DstPolicy>,
     pub weekdays: Option<Vec<u8>>,
     pub monthdays: Option<Vec<u8>>,
+    pub monthly_mode: Option<MonthlyMode>,
+    /// 第几个星期（1-5），配合 month_weekday 使用
+    pub month_week: Option<u8>,
+    /// 星期几（1-7，周一为 1）
+    pub month_weekday: Option<u8>,
+    /// 月末前 N 天（0 表示最后一天）
+    pub days_before_end: Option<u8>,
+    /// 每年重复的月、日
+    pub yearly_month: Option<u8>,
+    pub yearly_day: Option<u8>,
+    pub leap_day_policy: Option<LeapDayPolicy>,
+    /// 单次提醒的指定日期；高级规则的起始日期
+    pub remind_date: Option<NaiveDate>,
+    /// 高级重复规则（cron 表达式或 RRULE）
+    pub recurrence_rule: Option<String>,
+    /// 生效日期范围（按提醒时区，包含首尾两天）
+    pub starts_on: Option<NaiveDate>,
+    pub ends_on: Option<NaiveDate>,
+    /// 最多触发次数，达到后自动禁用
+    pub max_occurrences: Option<i32>,
+
+    // 间隔重复
+    pub interval_value: Option<i32>,
+    pub interval_unit: Option<IntervalUnit>,
+    /// 间隔计算的锚点时间（为空时使用创建时间）
+    pub anchor_at: Option<DateTime<Utc>>,
+    /// 每天生效时段 "HH:MM"，按分钟/小时间隔时每天从开始时间重新计算
+    pub window_start: Option<String>,
+    pub window_end: Option<String>,
+    /// 法定节假日不触发
+    pub skip_holidays: bool,
+
+    // 农历重复
+    /// 农历月 1-12，为空表示每个农历月
+    pub lunar_month: Option<u8>,
+    /// 农历日 1-30，小月没有三十时在廿九触发
+    pub lunar_day: Option<u8>,
+    pub lunar_leap_policy: Option<LunarLeapPolicy>,
+    /// 提前 N 天提醒
+    pub lunar_days_before: Option<i32>,
 
     // 状态
     pub is_enabled: bool,
+    /// 暂停到该时间（含），之后自动恢复
+    pub paused_until: Option<DateTime<Utc>>,
 
     // 重复设置
     pub repeat_on_close: bool,
@@ -116,11 +374,22 @@ pub struct Reminder {
     pub is_loop: bool,
     pub loop_interval: Option<i32>,
 
-    // Server酱通知
+    // 推送通知：各事件发送到的通知渠道（渠道 uuid）
+    pub trigger_channels: Vec<String>,
+    pub complete_channels: Vec<String>,
+    pub timeout_channels: Vec<String>,
+    pub timeout_minutes: Option<i32>,
+
+    // 兼容旧版前端：各事件是否发送到设置中的 Server酱 渠道（由渠道列表计算，不存储）
+    #[serde(default)]
     pub notify_on_trigger: bool,
+    #[serde(default)]
     pub notify_on_complete: bool,
+    #[serde(default)]
     pub notify_on_timeout: bool,
-    pub timeout_minutes: Option<i32>,
+
+    /// 重要提醒：勿扰期间照常弹窗
+    pub is_critical: bool,
 
     // 运行状态
     pub last_triggered_at: Option<DateTime<Utc>>,
@@ -140,6 +409,50 @@ pub struct Reminder {
     pub sync_at: Option<DateTime<Utc>>,
 }
 
+impl Reminder {
+    /// 每天的触发时间点（设置了多个时间点时使用 remind_times，否则为 remind_time）
+    pub fn time_slots(&self) -> Vec<&str> {
+        match self.remind_times {
+            Some(ref times) if !times.is_empty() => times.iter().map(|t| t.as_str()).collect(),
+            _ => vec![self.remind_time.as_str()],
+        }
+    }
+}
+
+/// 提醒的一次触发，同时给出提醒时区和本机时区的时间
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderOccurrence {
+    pub fire_at: DateTime<Utc>,
+    /// 提醒使用的 IANA 时区
+    pub time_zone: String,
+    /// 提醒时区的时间
+    pub zone_time: DateTime<FixedOffset>,
+    /// 本机时区的时间
+    pub local_time: DateTime<FixedOffset>,
+}
+
+/// 即将到来的触发来源
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpcomingSource {
+    /// 按重复规则计划的触发
+    Scheduled,
+    /// 延迟提醒
+    Snoozed,
+    /// 循环提醒
+    Loop,
+}
+
+/// 即将到来的一次触发
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingReminder {
+    pub reminder: Reminder,
+    pub fire_at: DateTime<Utc>,
+    pub source: UpcomingSource,
+}
+
 /// 创建提醒请求
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -148,17 +461,47 @@ pub struct CreateReminderRequest {
     pub content: Option<String>,
     pub links: Option<Vec<ReminderLink>>,
     pub remind_time: String,
+    pub remind_times: Option<Vec<String>>,
     pub remind_type: Option<RemindType>,
+    pub time_zone: Option<String>,
+    pub dst_policy: Option<DstPolicy>,
     pub weekdays: Option<Vec<u8>>,
     pub monthdays: Option<Vec<u8>>,
+    pub monthly_mode: Option<MonthlyMode>,
+    pub month_week: Option<u8>,
+    pub month_weekday: Option<u8>,
+    pub days_before_end: Option<u8>,
+    pub yearly_month: Option<u8>,
+    pub yearly_day: Option<u8>,
+    pub leap_day_policy: Option<LeapDayPolicy>,
+    pub remind_date: Option<NaiveDate>,
+    pub recurrence_rule: Option<String>,
+    pub starts_on: Option<NaiveDate>,
+    pub ends_on: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
+    pub interval_value: Option<i32>,
+    pub interval_unit: Option<IntervalUnit>,
+    pub anchor_at: Option<DateTime<Utc>>,
+    pub window_start: Option<String>,
+    pub window_end: Option<String>,
+    pub skip_holidays: Option<bool>,
+    pub lunar_month: Option<u8>,
+    pub lunar_day: Option<u8>,
+    pub lunar_leap_policy: Option<LunarLeapPolicy>,
+    pub lunar_days_before: Option<i32>,
     pub repeat_on_close: Option<bool>,
     pub repeat_interval: Option<i32>,
     pub is_loop: Option<bool>,
     pub loop_interval: Option<i32>,
+    pub trigger_channels: Option<Vec<String>>,
+    pub complete_channels: Option<Vec<String>>,
+    pub timeout_channels: Option<Vec<String>>,
+    /// 兼容旧版前端的 Server酱 开关，未指定对应渠道列表时转换为设置中 Server酱 渠道的增删
     pub notify_on_trigger: Option<bool>,
     pub notify_on_complete: Option<bool>,
     pub notify_on_timeout: Option<bool>,
     pub timeout_minutes: Option<i32>,
+    pub is_critical: Option<bool>,
 }
 
 /// 更新提醒请求
@@ -170,17 +513,51 @@ pub struct UpdateReminderRequest {
     pub content: Option<String>,
     pub links: Option<Vec<ReminderLink>>,
     pub remind_time: Option<String>,
+    pub remind_times: Option<Vec<String>>,
     pub remind_type: Option<RemindType>,
+    pub time_zone: Option<String>,
+    pub dst_policy: Option<DstPolicy>,
     pub weekdays: Option<Vec<u8>>,
     pub monthdays: Option<Vec<u8>>,
+    pub monthly_mode: Option<MonthlyMode>,
+    pub month_week: Option<u8>,
+    pub month_weekday: Option<u8>,
+    pub days_before_end: Option<u8>,
+    pub yearly_month: Option<u8>,
+    pub yearly_day: Option<u8>,
+    pub leap_day_policy: Option<LeapDayPolicy>,
+    pub remind_date: Option<NaiveDate>,
+    pub recurrence_rule: Option<String>,
+    /// 传 null 清除开始日期
+    #[serde(default, deserialize_with = "nullable")]
+    pub starts_on: Option<Option<NaiveDate>>,
+    /// 传 null 清除结束日期
+    #[serde(default, deserialize_with = "nullable")]
+    pub ends_on: Option<Option<NaiveDate>>,
+    pub max_occurrences: Option<i32>,
+    pub interval_value: Option<i32>,
+    pub interval_unit: Option<IntervalUnit>,
+    pub anchor_at: Option<DateTime<Utc>>,
+    pub window_start: Option<String>,
+    pub window_end: Option<String>,
+    pub skip_holidays: Option<bool>,
+    pub lunar_month: Option<u8>,
+    pub lunar_day: Option<u8>,
+    pub lunar_leap_policy: Option<LunarLeapPolicy>,
+    pub lunar_days_before: Option<i32>,
     pub is_enabled: Option<bool>,
     pub repeat_on_close: Option<bool>,
     pub repeat_interval: Option<i32>,
     pub is_loop: Option<bool>,
     pub loop_interval: Option<i32>,
+    pub trigger_channels: Option<Vec<String>>,
+    pub complete_channels: Option<Vec<String>>,
+    pub timeout_channels: Option<Vec<String>>,
+    /// 兼容旧版前端的 Server酱 开关，未指定对应渠道列表时转换为设置中 Server酱 渠道的增删
     pub notify_on_trigger: Option<bool>,
     pub notify_on_complete: Option<bool>,
     pub notify_on_timeout: Option<bool>,
     pub timeout_minutes: Option<i32>,
+    pub is_critical: Option<bool>,
     pub sort_order: Option<i32>,
 }
@@ -10,6 +10,13 @@ pub enum LogAction {
     Completed,
     Dismissed,
     Snoozed,
+    Missed,
+    /// 跳过（跳过下一次或暂停）
+    Skipped,
+    /// 超过结束日期或达到最大触发次数，自动禁用
+    Expired,
+    /// 超时未完成
+    TimedOut,
 }
 
 impl LogAction {
@@ -19,6 +26,10 @@ impl LogAction {
             Self::Completed => "completed",
             Self::Dismissed => "dismissed",
             Self::Snoozed => "snoozed",
+            Self::Missed => "missed",
+            Self::Skipped => "skipped",
+            Self::Expired => "expired",
+            Self::TimedOut => "timed_out",
         }
     }
 
@@ -27,6 +38,10 @@ impl LogAction {
             "completed" => Self::Completed,
             "dismissed" => Self::Dismissed,
             "snoozed" => Self::Snoozed,
+            "missed" => Self::Missed,
+            "skipped" => Self::Skipped,
+            "expired" => Self::Expired,
+            "timed_out" => Self::TimedOut,
             _ => Self::Triggered,
         }
     }
@@ -44,6 +59,8 @@ pub struct ReminderLog {
     pub action_at: Option<DateTime<Utc>>,
     pub snooze_until: Option<DateTime<Utc>>,
     pub note: Option<String>,
+    /// 触发的时间点（HH:MM）
+    pub slot: Option<String>,
     pub created_at: DateTime<Utc>,
     pub sync_status: SyncStatus,
     pub sync_at: Option<DateTime<Utc>>,
@@ -9,15 +9,181 @@ pub struct WindowPosition {
     pub height: u32,
 }
 
+/// 错过提醒的补发方式（休眠、关机或应用未运行期间错过的提醒）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissedReminderMode {
+    /// 逐个补发弹窗
+    FireLate,
+    /// 只显示弹窗（不重新触发提示音和推送），超出上限的排队显示
+    Summary,
+    /// 忽略，只记录日志
+    Ignore,
+}
+
+impl Default for MissedReminderMode {
+    fn default() -> Self {
+        Self::FireLate
+    }
+}
+
+/// 勿扰时段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHours {
+    /// "HH:MM"，结束时间早于开始时间时视为跨午夜
+    pub start: String,
+    pub end: String,
+    /// 生效的星期 1-7（周一为 1），为空表示每天
+    #[serde(default)]
+    pub weekdays: Vec<u8>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// SMTP 连接加密方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// 明文连接后升级为 TLS（通常为 587 端口）
+    StartTls,
+    /// 直接使用 TLS 连接（通常为 465 端口）
+    Tls,
+    /// 不加密，仅用于本地测试服务器
+    None,
+}
+
+impl Default for SmtpSecurity {
+    fn default() -> Self {
+        Self::StartTls
+    }
+}
+
+impl SmtpSecurity {
+    /// 加密方式对应的默认端口
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Self::StartTls => 587,
+            Self::Tls => 465,
+            Self::None => 25,
+        }
+    }
+}
+
+/// SMTP 发件服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmtpSettings {
+    pub host: String,
+    /// 端口，为空时使用加密方式的默认端口
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    /// 登录用户名，为空时不登录
+    #[serde(default)]
+    pub username: String,
+    /// 登录密码（Gmail、QQ 邮箱等需填写应用专用密码或授权码）
+    #[serde(default)]
+    pub password: String,
+    /// 发件人，如 "叮咚 <me@example.com>"，为空时使用用户名
+    #[serde(default)]
+    pub from: String,
+}
+
+/// 邮件摘要的发送频率
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    Daily,
+    Weekly,
+}
+
+impl Default for DigestFrequency {
+    fn default() -> Self {
+        Self::Daily
+    }
+}
+
 /// 应用设置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppSettings {
+    /// 旧版设置页的 Server酱 配置（通知已改由渠道发送，保存时同步到迁移出的 Server酱 渠道）
     pub serverchan_domain: Option<String>,
     pub serverchan_sendkey: Option<String>,
     pub auto_start: bool,
     pub default_snooze_interval: i32,
     pub window_position: Option<WindowPosition>,
+    #[serde(default)]
+    pub missed_reminder_mode: MissedReminderMode,
+    /// 手动开启的勿扰模式
+    #[serde(default)]
+    pub dnd_enabled: bool,
+    #[serde(default)]
+    pub quiet_hours: Vec<QuietHours>,
+    /// 旧版设置：勿扰期间是否仍发送 Server酱通知（已由渠道的 `allow_during_dnd` 取代）
+    #[serde(default = "default_true")]
+    pub dnd_allow_serverchan: bool,
+    /// 全屏演示、屏幕共享时暂缓弹窗
+    #[serde(default = "default_true")]
+    pub defer_popups_when_presenting: bool,
+    /// 弹窗最长暂缓分钟数，超过后即使仍在演示也照常弹出（0 表示一直暂缓）
+    #[serde(default = "default_max_popup_defer_minutes")]
+    pub max_popup_defer_minutes: i32,
+    /// 定时自动备份数据库
+    #[serde(default = "default_true")]
+    pub backup_enabled: bool,
+    /// 备份目录（为空时使用应用数据目录下的 backups）
+    #[serde(default)]
+    pub backup_dir: Option<String>,
+    /// 自动备份间隔（小时）
+    #[serde(default = "default_backup_interval_hours")]
+    pub backup_interval_hours: i32,
+    /// 保留的自动备份数量
+    #[serde(default = "default_backup_retention")]
+    pub backup_retention: i32,
+    /// 邮件发件服务器
+    #[serde(default)]
+    pub smtp: Option<SmtpSettings>,
+    /// 定期发送提醒记录摘要邮件
+    #[serde(default)]
+    pub digest_enabled: bool,
+    #[serde(default)]
+    pub digest_frequency: DigestFrequency,
+    /// 摘要发送时间 "HH:MM"
+    #[serde(default = "default_digest_time")]
+    pub digest_time: String,
+    /// 每周摘要的发送星期 1-7（周一为 1）
+    #[serde(default = "default_digest_weekday")]
+    pub digest_weekday: u8,
+    /// 摘要收件人
+    #[serde(default)]
+    pub digest_recipients: Vec<String>,
+}
+
+fn default_backup_interval_hours() -> i32 {
+    24
+}
+
+fn default_backup_retention() -> i32 {
+    7
+}
+
+fn default_max_popup_defer_minutes() -> i32 {
+    30
+}
+
+fn default_digest_time() -> String {
+    "08:00".to_string()
+}
+
+fn default_digest_weekday() -> u8 {
+    1
 }
 
 impl Default for AppSettings {
@@ -28,6 +194,22 @@ impl Default for AppSettings {
             auto_start: false,
             default_snooze_interval: 5,
             window_position: None,
+            missed_reminder_mode: MissedReminderMode::default(),
+            dnd_enabled: false,
+            quiet_hours: vec![],
+            dnd_allow_serverchan: true,
+            defer_popups_when_presenting: true,
+            max_popup_defer_minutes: default_max_popup_defer_minutes(),
+            backup_enabled: true,
+            backup_dir: None,
+            backup_interval_hours: default_backup_interval_hours(),
+            backup_retention: default_backup_retention(),
+            smtp: None,
+            digest_enabled: false,
+            digest_frequency: DigestFrequency::default(),
+            digest_time: default_digest_time(),
+            digest_weekday: default_digest_weekday(),
+            digest_recipients: vec![],
         }
     }
 }
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+use crate::notification::robot;
+
+const DEFAULT_SERVER: &str = "https://api.day.app";
+
+/// Bark 通知级别
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum BarkLevel {
+    /// 立即亮屏显示
+    #[default]
+    Active,
+    /// 时效性通知，可在专注模式下显示
+    TimeSensitive,
+    /// 仅添加到通知列表，不亮屏
+    Passive,
+    /// 重要警告，静音模式下也会响铃
+    Critical,
+}
+
+/// Bark 配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BarkConfig {
+    pub device_key: String,
+    /// 服务器地址（为空时使用官方服务器，可填写自建服务）
+    #[serde(default)]
+    pub server_url: Option<String>,
+    #[serde(default)]
+    pub level: BarkLevel,
+    /// 铃声名称（如 alarm、bell，为空时使用默认铃声）
+    #[serde(default)]
+    pub sound: Option<String>,
+    /// 通知分组
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+/// Bark 通知渠道（iOS）
+pub struct Bark {
+    url: Url,
+    config: BarkConfig,
+}
+
+#[derive(Deserialize)]
+struct BarkResponse {
+    code: i64,
+    message: Option<String>,
+}
+
+impl Bark {
+    pub fn new(config: BarkConfig) -> AppResult<Self> {
+        if config.device_key.trim().is_empty() {
+            return Err(AppError::Validation("Bark Device Key 不能为空".to_string()));
+        }
+        let server = config.server_url.as_deref().map(str::trim).filter(|s| !s.is_empty()).unwrap_or(DEFAULT_SERVER);
+        let url = parse_url(&format!("{}/push", server.trim_end_matches('/')), "Bark 服务器")?;
+        Ok(Self { url, config })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let mut body = json!({
+            "device_key": self.config.device_key.trim(),
+            "title": message.title,
+            "body": robot::markdown_text(message, false),
+            "level": self.config.level,
+        });
+        if let Some(sound) = self.config.sound.as_deref().filter(|s| !s.is_empty()) {
+            body["sound"] = json!(sound);
+        }
+        if let Some(group) = self.config.group.as_deref().filter(|g| !g.is_empty()) {
+            body["group"] = json!(group);
+        }
+        // 点击通知打开第一个链接
+        if let Some(link) = robot::links(message).first() {
+            body["url"] = json!(link.url);
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Bark {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = self.body(message);
+        let response = send_with_retry(RetryPolicy::default(), || client.post(self.url.clone()).json(&body)).await?;
+
+        let result: BarkResponse = response.json().await?;
+        if result.code != 200 {
+            return Err(AppError::Notification(format!(
+                "Bark 返回错误 {}: {}",
+                result.code,
+                result.message.unwrap_or_default()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+
+    #[tokio::test]
+    async fn pushes_json_to_server() {
+        let server = TestServer::start(vec![(200, r#"{"code":200,"message":"success"}"#)]).await;
+        let bark = Bark::new(BarkConfig {
+            device_key: " device ".to_string(),
+            server_url: Some(server.url.clone()),
+            level: BarkLevel::TimeSensitive,
+            sound: Some("alarm".to_string()),
+            group: Some(String::new()),
+        })
+        .unwrap();
+        bark.send(&NotificationMessage::test("Bark")).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/push");
+        assert_eq!(
+            request.json(),
+            json!({
+                "device_key": "device",
+                "title": "叮咚 测试",
+                "body": "这是一条测试消息，说明 Bark 配置成功！",
+                "level": "timeSensitive",
+                "sound": "alarm",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_api_error() {
+        let server = TestServer::start(vec![(200, r#"{"code":400,"message":"failed to get device token"}"#)]).await;
+        let bark = Bark::new(BarkConfig {
+            device_key: "device".to_string(),
+            server_url: Some(server.url.clone()),
+            level: BarkLevel::default(),
+            sound: None,
+            group: None,
+        })
+        .unwrap();
+        let result = bark.send(&NotificationMessage::test("Bark")).await;
+        match result {
+            Err(AppError::Notification(message)) => assert!(message.contains("400"), "{}", message),
+            other => panic!("应返回通知错误: {:?}", other),
+        }
+    }
+}
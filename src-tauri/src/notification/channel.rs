@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+use crate::error::{AppError, AppResult};
+use crate::models::channel::ChannelKind;
+use crate::models::reminder::Reminder;
+use crate::notification::bark::Bark;
+use crate::notification::dingtalk::DingTalk;
+use crate::notification::email::Email;
+use crate::notification::feishu::Feishu;
+use crate::notification::gotify::Gotify;
+use crate::notification::ntfy::Ntfy;
+use crate::notification::pushplus::PushPlus;
+use crate::notification::serverchan::ServerChan;
+use crate::notification::telegram::Telegram;
+use crate::notification::webhook::Webhook;
+use crate::notification::wecom::WeCom;
+use crate::storage::channel_repo;
+
+/// 推送通知的事件类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationEvent {
+    Trigger,
+    Complete,
+    Timeout,
+    /// 测试渠道配置
+    Test,
+}
+
+impl NotificationEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trigger => "trigger",
+            Self::Complete => "complete",
+            Self::Timeout => "timeout",
+            Self::Test => "test",
+        }
+    }
+}
+
+/// 发送给通知渠道的消息
+#[derive(Debug, Clone)]
+pub struct NotificationMessage {
+    pub event: NotificationEvent,
+    pub title: String,
+    pub content: String,
+    /// 关联的提醒（测试消息为空）
+    pub reminder: Option<Reminder>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl NotificationMessage {
+    fn new(event: NotificationEvent, title: String, content: String, reminder: Option<&Reminder>) -> Self {
+        Self { event, title, content, reminder: reminder.cloned(), occurred_at: Utc::now() }
+    }
+
+    pub fn trigger(reminder: &Reminder) -> Self {
+        Self::new(
+            NotificationEvent::Trigger,
+            format!("提醒触发: {}", reminder.title),
+            reminder.content.clone(),
+            Some(reminder),
+        )
+    }
+
+    pub fn complete(reminder: &Reminder) -> Self {
+        Self::new(
+            NotificationEvent::Complete,
+            format!("提醒完成: {}", reminder.title),
+            format!("提醒「{}」已完成", reminder.title),
+            Some(reminder),
+        )
+    }
+
+    pub fn timeout(reminder: &Reminder) -> Self {
+        Self::new(
+            NotificationEvent::Timeout,
+            format!("提醒超时: {}", reminder.title),
+            format!("提醒「{}」已超时未完成", reminder.title),
+            Some(reminder),
+        )
+    }
+
+    /// 测试消息，`name` 为渠道名称
+    pub fn test(name: &str) -> Self {
+        Self::new(
+            NotificationEvent::Test,
+            "叮咚 测试".to_string(),
+            format!("这是一条测试消息，说明 {} 配置成功！", name),
+            None,
+        )
+    }
+}
+
+/// 通知渠道：把消息推送到外部服务
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()>;
+}
+
+/// 解析渠道配置
+pub fn parse_config<T: DeserializeOwned>(config: &serde_json::Value) -> AppResult<T> {
+    serde_json::from_value(config.clone())
+        .map_err(|e| AppError::Validation(format!("渠道配置无效: {}", e)))
+}
+
+/// 根据渠道类型和配置创建渠道实例（配置无效时返回错误）
+pub fn build(kind: ChannelKind, config: &serde_json::Value) -> AppResult<Box<dyn NotificationChannel>> {
+    Ok(match kind {
+        ChannelKind::ServerChan => Box::new(ServerChan::new(parse_config(config)?)?),
+        ChannelKind::Webhook => Box::new(Webhook::new(parse_config(config)?)?),
+        ChannelKind::DingTalk => Box::new(DingTalk::new(parse_config(config)?)?),
+        ChannelKind::WeCom => Box::new(WeCom::new(parse_config(config)?)?),
+        ChannelKind::Feishu => Box::new(Feishu::new(parse_config(config)?)?),
+        ChannelKind::Telegram => Box::new(Telegram::new(parse_config(config)?)?),
+        ChannelKind::Bark => Box::new(Bark::new(parse_config(config)?)?),
+        ChannelKind::Ntfy => Box::new(Ntfy::new(parse_config(config)?)?),
+        ChannelKind::Gotify => Box::new(Gotify::new(parse_config(config)?)?),
+        ChannelKind::PushPlus => Box::new(PushPlus::new(parse_config(config)?)?),
+        ChannelKind::Email => Box::new(Email::new(parse_config(config)?)?),
+    })
+}
+
+/// 单次推送请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 推送请求使用的 HTTP 客户端
+pub fn http_client() -> AppResult<Client> {
+    Ok(Client::builder().timeout(REQUEST_TIMEOUT).build()?)
+}
+
+/// 校验推送地址（只接受 http/https）
+pub fn parse_url(url: &str, name: &str) -> AppResult<Url> {
+    Url::parse(url.trim())
+        .ok()
+        .filter(|u| matches!(u.scheme(), "http" | "https"))
+        .ok_or_else(|| AppError::Validation(format!("{}地址无效: {}", name, url)))
+}
+
+/// 推送请求的重试策略：失败后按指数退避等待（1 倍、2 倍、4 倍……基础间隔）再重试
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 最多尝试次数（含第一次）
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 3, base_delay: Duration::from_secs(1) }
+    }
+}
+
+impl RetryPolicy {
+    /// 第 `attempt` 次（从 1 开始）失败后的等待时间
+    fn delay(&self, attempt: u32) -> Duration {
+        self.base_delay * 2u32.saturating_pow(attempt - 1)
+    }
+}
+
+/// 是否值得重试（限流、请求超时和服务端错误）
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::REQUEST_TIMEOUT
+}
+
+/// 发送 HTTP 请求，网络错误或可重试的状态码按策略重试，返回成功的响应
+///
+/// `build` 每次尝试都会调用，用于重新构建请求。
+pub async fn send_with_retry<F>(policy: RetryPolicy, build: F) -> AppResult<Response>
+where
+    F: Fn() -> RequestBuilder,
+{
+    let mut attempt = 1;
+    loop {
+        let error = match build().send().await {
+            Ok(response) if response.status().is_success() => return Ok(response),
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                let body: String = body.trim().chars().take(200).collect();
+                let error = AppError::Notification(format!("请求失败: {} {}", status, body).trim_end().to_string());
+                if !is_retryable(status) {
+                    return Err(error);
+                }
+                error
+            }
+            Err(e) => AppError::from(e),
+        };
+
+        if attempt >= policy.max_attempts {
+            return Err(error);
+        }
+        let delay = policy.delay(attempt);
+        eprintln!("[通知渠道] 第 {} 次发送失败，{} 秒后重试: {}", attempt, delay.as_secs_f32(), error);
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// 向指定渠道发送消息
+///
+/// 不存在或已停用的渠道直接跳过；勿扰期间（`muted`）只发送允许勿扰时发送的渠道。
+/// 单个渠道失败只记录日志，不影响其他渠道。
+/// 发送在后台任务中进行，不阻塞调用方（调度器不等待网络请求）。
+pub fn dispatch(channel_uuids: Vec<String>, message: NotificationMessage, muted: bool) {
+    if channel_uuids.is_empty() {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        send_all(&channel_uuids, &message, muted).await;
+    });
+}
+
+/// 依次发送到各渠道
+async fn send_all(channel_uuids: &[String], message: &NotificationMessage, muted: bool) {
+    for uuid in channel_uuids {
+        let channel = match channel_repo::get_by_uuid(uuid) {
+            Ok(Some(channel)) => channel,
+            Ok(None) => {
+                eprintln!("[通知渠道] 渠道不存在: {}", uuid);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("[通知渠道] 读取渠道失败 {}: {}", uuid, e);
+                continue;
+            }
+        };
+
+        if !channel.is_enabled || (muted && !channel.allow_during_dnd) {
+            continue;
+        }
+
+        let result = match build(channel.kind, &channel.config) {
+            Ok(sender) => sender.send(message).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            eprintln!("[通知渠道] {}（{}）发送{}通知失败: {}", channel.name, channel.kind.as_str(), message.event.as_str(), e);
+        }
+    }
+}
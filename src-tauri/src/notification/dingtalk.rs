@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::AppResult;
+use crate::notification::channel::{parse_url, NotificationChannel, NotificationMessage};
+use crate::notification::robot::{self, RobotFormat};
+
+/// 钉钉群机器人配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DingTalkConfig {
+    /// 机器人 Webhook 地址（含 access_token）
+    pub webhook: String,
+    /// 安全设置为「加签」时的密钥（SEC 开头）
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// 安全设置为「自定义关键词」时的关键词，消息中不包含时自动附加
+    #[serde(default)]
+    pub keyword: Option<String>,
+    #[serde(default)]
+    pub format: RobotFormat,
+}
+
+/// 钉钉群机器人通知渠道
+pub struct DingTalk {
+    url: Url,
+    secret: Option<String>,
+    keyword: Option<String>,
+    format: RobotFormat,
+}
+
+impl DingTalk {
+    pub fn new(config: DingTalkConfig) -> AppResult<Self> {
+        let non_empty = |s: Option<String>| s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        Ok(Self {
+            url: parse_url(&config.webhook, "钉钉 Webhook ")?,
+            secret: non_empty(config.secret),
+            keyword: non_empty(config.keyword),
+            format: config.format,
+        })
+    }
+
+    /// 加签：timestamp（毫秒）+ "\n" + secret 以 secret 为密钥做 HMAC-SHA256，Base64 后附加到地址上
+    fn signed_url(&self, timestamp_millis: i64) -> Url {
+        let mut url = self.url.clone();
+        if let Some(ref secret) = self.secret {
+            let timestamp = timestamp_millis.to_string();
+            let sign = robot::sign(secret.as_bytes(), format!("{}\n{}", timestamp, secret).as_bytes());
+            url.query_pairs_mut()
+                .append_pair("timestamp", &timestamp)
+                .append_pair("sign", &sign);
+        }
+        url
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let links = robot::links(message);
+        let card = self.format == RobotFormat::Card && !links.is_empty();
+
+        let mut text = format!("### {}", message.title);
+        let content = robot::markdown_text(message, !card);
+        if !content.is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&content);
+        }
+        if let Some(ref keyword) = self.keyword {
+            if !text.contains(keyword.as_str()) {
+                text.push_str(&format!("\n\n{}", keyword));
+            }
+        }
+
+        if card {
+            let buttons: Vec<_> = links.iter()
+                .map(|link| json!({ "title": link.name, "actionURL": link.url }))
+                .collect();
+            json!({
+                "msgtype": "actionCard",
+                "actionCard": { "title": message.title, "text": text, "btnOrientation": "0", "btns": buttons },
+            })
+        } else {
+            json!({
+                "msgtype": "markdown",
+                "markdown": { "title": message.title, "text": text },
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for DingTalk {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        robot::post(self.signed_url(Utc::now().timestamp_millis()), &self.body(message), "钉钉").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::robot::tests::{message_with_links, FAST_RETRY};
+    use crate::notification::test_server::TestServer;
+
+    /// 固定的签名时间戳（毫秒）
+    const TIMESTAMP: i64 = 1_700_000_000_000;
+
+    fn dingtalk(url: &str, secret: Option<&str>, keyword: Option<&str>, format: RobotFormat) -> DingTalk {
+        DingTalk::new(DingTalkConfig {
+            webhook: format!("{}/robot/send?access_token=abc", url),
+            secret: secret.map(str::to_string),
+            keyword: keyword.map(str::to_string),
+            format,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn signs_url_and_sends_markdown() {
+        let server = TestServer::start(vec![(200, r#"{"errcode":0,"errmsg":"ok"}"#)]).await;
+        let robot = dingtalk(&server.url, Some(" SECtest "), None, RobotFormat::Markdown);
+        let message = message_with_links("10 点开会", &[("会议室", "https://example.com/room")]);
+        robot::post_with(robot.signed_url(TIMESTAMP), &robot.body(&message), "钉钉", FAST_RETRY).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.path,
+            "/robot/send?access_token=abc&timestamp=1700000000000&sign=aZLLrriXgn05YbwaGR7knYsLeJADjr9NwLaNNKpxh4g%3D"
+        );
+        assert_eq!(
+            request.json(),
+            json!({
+                "msgtype": "markdown",
+                "markdown": {
+                    "title": "提醒触发: 站会",
+                    "text": "### 提醒触发: 站会\n\n10 点开会\n\n[会议室](https://example.com/room)",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn leaves_url_unsigned_without_secret() {
+        let robot = dingtalk("http://127.0.0.1:1", Some("  "), None, RobotFormat::Markdown);
+        assert_eq!(robot.signed_url(TIMESTAMP).as_str(), "http://127.0.0.1:1/robot/send?access_token=abc");
+    }
+
+    #[test]
+    fn appends_missing_keyword() {
+        let robot = dingtalk("http://127.0.0.1:1", None, Some("叮咚"), RobotFormat::Markdown);
+        let text = robot.body(&NotificationMessage::test("钉钉"))["markdown"]["text"].clone();
+        assert_eq!(text, "### 叮咚 测试\n\n这是一条测试消息，说明 钉钉 配置成功！");
+
+        let robot = dingtalk("http://127.0.0.1:1", None, Some("提醒"), RobotFormat::Markdown);
+        let text = robot.body(&NotificationMessage::test("钉钉"))["markdown"]["text"].clone();
+        assert_eq!(text, "### 叮咚 测试\n\n这是一条测试消息，说明 钉钉 配置成功！\n\n提醒");
+    }
+
+    #[test]
+    fn card_format_shows_links_as_buttons() {
+        let robot = dingtalk("http://127.0.0.1:1", None, None, RobotFormat::Card);
+        let message = message_with_links("10 点开会", &[("会议室", "https://example.com/room"), ("议程", "https://example.com/agenda")]);
+        assert_eq!(
+            robot.body(&message),
+            json!({
+                "msgtype": "actionCard",
+                "actionCard": {
+                    "title": "提醒触发: 站会",
+                    "text": "### 提醒触发: 站会\n\n10 点开会",
+                    "btnOrientation": "0",
+                    "btns": [
+                        { "title": "会议室", "actionURL": "https://example.com/room" },
+                        { "title": "议程", "actionURL": "https://example.com/agenda" },
+                    ],
+                },
+            })
+        );
+
+        // 没有链接时退回 Markdown
+        assert_eq!(robot.body(&NotificationMessage::test("钉钉"))["msgtype"], "markdown");
+    }
+}
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::error::{AppError, AppResult};
+use crate::models::settings::{SmtpSecurity, SmtpSettings};
+use crate::notification::channel::{NotificationChannel, NotificationMessage};
+use crate::notification::escape_html;
+use crate::notification::robot::links;
+use crate::storage::settings_repo;
+
+/// SMTP 连接与命令超时
+const SMTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 邮件渠道配置（发件服务器使用设置中的 SMTP 配置）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailConfig {
+    /// 收件人
+    pub to: Vec<String>,
+}
+
+/// 邮件通知渠道
+pub struct Email {
+    to: Vec<Mailbox>,
+}
+
+impl Email {
+    pub fn new(config: EmailConfig) -> AppResult<Self> {
+        if config.to.is_empty() {
+            return Err(AppError::Validation("收件人不能为空".to_string()));
+        }
+        let to = config.to.iter().map(|addr| parse_mailbox(addr)).collect::<AppResult<_>>()?;
+        Ok(Self { to })
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Email {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let smtp = settings_repo::get_all()?
+            .smtp
+            .ok_or_else(|| AppError::Validation("尚未配置 SMTP 发件服务器".to_string()))?;
+        send_mail(&smtp, &self.to, &message.title, message_text(message), message_html(message)).await
+    }
+}
+
+/// 解析邮箱地址（支持 "名称 <地址>" 格式）
+pub fn parse_mailbox(addr: &str) -> AppResult<Mailbox> {
+    addr.trim()
+        .parse()
+        .map_err(|_| AppError::Validation(format!("邮箱地址无效: {}", addr)))
+}
+
+/// 发件人，未填写时使用登录用户名
+fn sender(smtp: &SmtpSettings) -> AppResult<Mailbox> {
+    let from = if smtp.from.trim().is_empty() { &smtp.username } else { &smtp.from };
+    if from.trim().is_empty() {
+        return Err(AppError::Validation("发件人不能为空".to_string()));
+    }
+    parse_mailbox(from)
+}
+
+/// 根据 SMTP 配置创建连接
+fn transport(smtp: &SmtpSettings) -> AppResult<AsyncSmtpTransport<Tokio1Executor>> {
+    let host = smtp.host.trim();
+    if host.is_empty() {
+        return Err(AppError::Validation("SMTP 服务器不能为空".to_string()));
+    }
+
+    let builder = match smtp.security {
+        SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+        SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+        SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)),
+    }
+    .map_err(|e| AppError::Validation(format!("SMTP 服务器无效: {}", e)))?;
+
+    let mut builder = builder
+        .port(smtp.port.unwrap_or_else(|| smtp.security.default_port()))
+        .timeout(Some(SMTP_TIMEOUT));
+    if !smtp.username.trim().is_empty() {
+        builder = builder.credentials(Credentials::new(smtp.username.trim().to_string(), smtp.password.clone()));
+    }
+    Ok(builder.build())
+}
+
+/// 发送同时包含纯文本和 HTML 正文的邮件
+pub async fn send_mail(smtp: &SmtpSettings, to: &[Mailbox], subject: &str, text: String, html: String) -> AppResult<()> {
+    let mut builder = Message::builder().from(sender(smtp)?).subject(subject);
+    for mailbox in to {
+        builder = builder.to(mailbox.clone());
+    }
+    let email = builder
+        .multipart(MultiPart::alternative_plain_html(text, html))
+        .map_err(|e| AppError::Validation(format!("邮件内容无效: {}", e)))?;
+
+    transport(smtp)?
+        .send(email)
+        .await
+        .map_err(|e| AppError::Notification(format!("邮件发送失败: {}", e)))?;
+    Ok(())
+}
+
+/// 测试 SMTP 配置：发送一封测试邮件，`to` 为空时发给发件人自己
+pub async fn test_connection(smtp: &SmtpSettings, to: Option<&str>) -> AppResult<bool> {
+    let to = match to.map(str::trim).filter(|t| !t.is_empty()) {
+        Some(to) => parse_mailbox(to)?,
+        None => sender(smtp)?,
+    };
+    let message = NotificationMessage::test("邮件");
+    send_mail(smtp, &[to], &message.title, message_text(&message), message_html(&message)).await?;
+    Ok(true)
+}
+
+/// 纯文本正文
+fn message_text(message: &NotificationMessage) -> String {
+    let mut text = message.content.trim().to_string();
+    for link in links(message) {
+        text.push_str(&format!("\n\n{}: {}", link.name, link.url));
+    }
+    text
+}
+
+/// HTML 正文
+fn message_html(message: &NotificationMessage) -> String {
+    let mut html = format!("<h3>{}</h3>", escape_html(&message.title));
+    if !message.content.trim().is_empty() {
+        html.push_str(&format!("<p>{}</p>", escape_html(message.content.trim()).replace('\n', "<br>")));
+    }
+    for link in links(message) {
+        html.push_str(&format!(
+            "<p><a href=\"{}\">{}</a></p>",
+            escape_html(&link.url).replace('"', "&quot;"),
+            escape_html(&link.name)
+        ));
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::robot::tests::message_with_links;
+    use crate::notification::test_server::SmtpSink;
+    use crate::storage::database::init_test_database;
+
+    fn smtp(port: u16) -> SmtpSettings {
+        SmtpSettings {
+            host: "127.0.0.1".to_string(),
+            port: Some(port),
+            security: SmtpSecurity::None,
+            username: String::new(),
+            password: String::new(),
+            from: "叮咚 <dingdong@example.com>".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_non_ascii_subject_and_body() {
+        let sink = SmtpSink::start().await;
+        let to = [parse_mailbox("张三 <zhang@example.com>").unwrap(), parse_mailbox("li@example.com").unwrap()];
+        let subject = "提醒触发: 喝水（每小时一次，记得起身活动一下，顺便看看窗外）";
+        send_mail(&smtp(sink.port), &to, subject, "多喝热水 ☕\n第二行".to_string(), "<p>多喝热水 ☕</p>".to_string())
+            .await
+            .unwrap();
+
+        let mails = sink.mails();
+        assert_eq!(mails.len(), 1);
+        let mail = &mails[0];
+        assert_eq!(mail.from, "<dingdong@example.com>");
+        assert_eq!(mail.to, vec!["<zhang@example.com>", "<li@example.com>"]);
+        assert_eq!(mail.header("Subject").as_deref(), Some(subject));
+        assert_eq!(mail.header("From").as_deref(), Some("叮咚 <dingdong@example.com>"));
+        assert_eq!(mail.header("To").as_deref(), Some("张三 <zhang@example.com>, li@example.com"));
+        assert_eq!(mail.part("text/plain").as_deref(), Some("多喝热水 ☕\n第二行"));
+        assert_eq!(mail.part("text/html").as_deref(), Some("<p>多喝热水 ☕</p>"));
+    }
+
+    #[tokio::test]
+    async fn test_connection_defaults_to_sender() {
+        let sink = SmtpSink::start().await;
+        assert!(test_connection(&smtp(sink.port), None).await.unwrap());
+        assert!(test_connection(&smtp(sink.port), Some(" ops@example.com ")).await.unwrap());
+
+        let mails = sink.mails();
+        assert_eq!(mails.len(), 2);
+        assert_eq!(mails[0].to, vec!["<dingdong@example.com>"]);
+        assert_eq!(mails[1].to, vec!["<ops@example.com>"]);
+        assert_eq!(mails[0].header("Subject").as_deref(), Some("叮咚 测试"));
+        assert_eq!(mails[0].part("text/plain").as_deref(), Some("这是一条测试消息，说明 邮件 配置成功！"));
+    }
+
+    #[tokio::test]
+    async fn test_connection_reports_invalid_settings() {
+        let sink = SmtpSink::start().await;
+        assert!(matches!(test_connection(&smtp(sink.port), Some("不是邮箱")).await, Err(AppError::Validation(_))));
+
+        let no_sender = SmtpSettings { from: String::new(), ..smtp(sink.port) };
+        assert!(matches!(test_connection(&no_sender, None).await, Err(AppError::Validation(_))));
+        assert!(sink.mails().is_empty());
+
+        // 端口上没有服务
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        assert!(matches!(test_connection(&smtp(closed), None).await, Err(AppError::Notification(_))));
+    }
+
+    #[tokio::test]
+    async fn channel_sends_with_settings_smtp() {
+        init_test_database();
+        let sink = SmtpSink::start().await;
+        settings_repo::set("smtp", &serde_json::to_string(&smtp(sink.port)).unwrap()).unwrap();
+
+        let email = Email::new(EmailConfig { to: vec!["王五 <wang@example.com>".to_string()] }).unwrap();
+        let message = message_with_links("10 点 <站会>\n带上周报", &[("会议室 & 链接", "https://example.com/room?a=1&b=\"2\"")]);
+        email.send(&message).await.unwrap();
+
+        let mails = sink.mails();
+        assert_eq!(mails.len(), 1);
+        let mail = &mails[0];
+        assert_eq!(mail.to, vec!["<wang@example.com>"]);
+        assert_eq!(mail.header("Subject").as_deref(), Some("提醒触发: 站会"));
+        assert_eq!(
+            mail.part("text/plain").as_deref(),
+            Some("10 点 <站会>\n带上周报\n\n会议室 & 链接: https://example.com/room?a=1&b=\"2\"")
+        );
+        assert_eq!(
+            mail.part("text/html").as_deref(),
+            Some(concat!(
+                "<h3>提醒触发: 站会</h3><p>10 点 &lt;站会&gt;<br>带上周报</p>",
+                "<p><a href=\"https://example.com/room?a=1&amp;b=&quot;2&quot;\">会议室 &amp; 链接</a></p>",
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_empty_or_invalid_recipients() {
+        assert!(matches!(Email::new(EmailConfig { to: vec![] }), Err(AppError::Validation(_))));
+        assert!(matches!(Email::new(EmailConfig { to: vec!["a@".to_string()] }), Err(AppError::Validation(_))));
+    }
+}
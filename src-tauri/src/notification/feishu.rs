@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::AppResult;
+use crate::notification::channel::{parse_url, NotificationChannel, NotificationMessage};
+use crate::notification::robot::{self, RobotFormat};
+
+/// 飞书群机器人配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeishuConfig {
+    /// 机器人 Webhook 地址
+    pub webhook: String,
+    /// 安全设置为「签名校验」时的密钥
+    #[serde(default)]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub format: RobotFormat,
+}
+
+/// 飞书群机器人通知渠道
+pub struct Feishu {
+    url: Url,
+    secret: Option<String>,
+    format: RobotFormat,
+}
+
+impl Feishu {
+    pub fn new(config: FeishuConfig) -> AppResult<Self> {
+        Ok(Self {
+            url: parse_url(&config.webhook, "飞书 Webhook ")?,
+            secret: config.secret.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()),
+            format: config.format,
+        })
+    }
+
+    /// 消息卡片：Markdown 格式时链接放在正文中，卡片格式时链接显示为按钮
+    fn card(&self, message: &NotificationMessage) -> serde_json::Value {
+        let links = robot::links(message);
+        let buttons = self.format == RobotFormat::Card && !links.is_empty();
+
+        let mut elements = vec![];
+        let content = robot::markdown_text(message, !buttons);
+        if !content.is_empty() {
+            elements.push(json!({ "tag": "div", "text": { "tag": "lark_md", "content": content } }));
+        }
+        if buttons {
+            let actions: Vec<_> = links.iter()
+                .map(|link| json!({
+                    "tag": "button",
+                    "text": { "tag": "plain_text", "content": link.name },
+                    "url": link.url,
+                    "type": "default",
+                }))
+                .collect();
+            elements.push(json!({ "tag": "action", "actions": actions }));
+        }
+
+        json!({
+            "header": { "title": { "tag": "plain_text", "content": message.title }, "template": "blue" },
+            "elements": elements,
+        })
+    }
+
+    /// 消息体，`timestamp` 为签名使用的时间戳（秒）
+    fn body(&self, message: &NotificationMessage, timestamp: i64) -> serde_json::Value {
+        let mut body = json!({ "msg_type": "interactive", "card": self.card(message) });
+
+        // 签名校验：timestamp + "\n" + secret 作为密钥对空串做 HMAC-SHA256，Base64 后随消息发送
+        if let Some(ref secret) = self.secret {
+            let timestamp = timestamp.to_string();
+            let sign = robot::sign(format!("{}\n{}", timestamp, secret).as_bytes(), b"");
+            body["timestamp"] = json!(timestamp);
+            body["sign"] = json!(sign);
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Feishu {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        robot::post(self.url.clone(), &self.body(message, Utc::now().timestamp()), "飞书").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::robot::tests::{message_with_links, FAST_RETRY};
+    use crate::notification::test_server::TestServer;
+
+    /// 固定的签名时间戳（秒）
+    const TIMESTAMP: i64 = 1_700_000_000;
+
+    fn feishu(url: &str, secret: Option<&str>, format: RobotFormat) -> Feishu {
+        Feishu::new(FeishuConfig {
+            webhook: format!("{}/open-apis/bot/v2/hook/abc", url),
+            secret: secret.map(str::to_string),
+            format,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_signed_card() {
+        let server = TestServer::start(vec![(200, r#"{"code":0,"msg":"success"}"#)]).await;
+        let robot = feishu(&server.url, Some("feishu-secret"), RobotFormat::Markdown);
+        let message = message_with_links("10 点开会", &[("会议室", "https://example.com/room")]);
+        robot::post_with(robot.url.clone(), &robot.body(&message, TIMESTAMP), "飞书", FAST_RETRY).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/open-apis/bot/v2/hook/abc");
+        assert_eq!(
+            request.json(),
+            json!({
+                "msg_type": "interactive",
+                "timestamp": "1700000000",
+                "sign": "OrBzY1Y01Gq+HgJsl+7OfWcMVwc7YocohQm5iiZwjhU=",
+                "card": {
+                    "header": { "title": { "tag": "plain_text", "content": "提醒触发: 站会" }, "template": "blue" },
+                    "elements": [
+                        { "tag": "div", "text": { "tag": "lark_md", "content": "10 点开会\n\n[会议室](https://example.com/room)" } },
+                    ],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn card_format_shows_links_as_buttons() {
+        let robot = feishu("http://127.0.0.1:1", None, RobotFormat::Card);
+        let message = message_with_links("10 点开会", &[("会议室", "https://example.com/room")]);
+        let body = robot.body(&message, TIMESTAMP);
+        assert!(body.get("sign").is_none() && body.get("timestamp").is_none());
+        assert_eq!(
+            body["card"]["elements"],
+            json!([
+                { "tag": "div", "text": { "tag": "lark_md", "content": "10 点开会" } },
+                {
+                    "tag": "action",
+                    "actions": [{
+                        "tag": "button",
+                        "text": { "tag": "plain_text", "content": "会议室" },
+                        "url": "https://example.com/room",
+                        "type": "default",
+                    }],
+                },
+            ])
+        );
+    }
+}
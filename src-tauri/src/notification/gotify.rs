@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+use crate::notification::robot;
+
+fn default_priority() -> u8 {
+    5
+}
+
+/// Gotify 配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GotifyConfig {
+    /// 自建服务器地址
+    pub server_url: String,
+    /// 应用令牌
+    pub token: String,
+    /// 优先级 0-10（默认 5，Android 客户端 8 及以上会弹出通知）
+    #[serde(default = "default_priority")]
+    pub priority: u8,
+}
+
+/// Gotify 通知渠道
+pub struct Gotify {
+    url: Url,
+    token: String,
+    priority: u8,
+}
+
+impl Gotify {
+    pub fn new(config: GotifyConfig) -> AppResult<Self> {
+        if config.token.trim().is_empty() {
+            return Err(AppError::Validation("Gotify 应用令牌不能为空".to_string()));
+        }
+        if config.priority > 10 {
+            return Err(AppError::Validation("Gotify 优先级必须在 0-10 之间".to_string()));
+        }
+        let url = parse_url(&format!("{}/message", config.server_url.trim().trim_end_matches('/')), "Gotify 服务器")?;
+        Ok(Self { url, token: config.token.trim().to_string(), priority: config.priority })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let mut extras = json!({ "client::display": { "contentType": "text/markdown" } });
+        // 点击通知打开第一个链接
+        if let Some(link) = robot::links(message).first() {
+            extras["client::notification"] = json!({ "click": { "url": link.url } });
+        }
+
+        json!({
+            "title": message.title,
+            "message": robot::markdown_text(message, true),
+            "priority": self.priority,
+            "extras": extras,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Gotify {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = self.body(message);
+
+        send_with_retry(RetryPolicy::default(), || {
+            client.post(self.url.clone()).header("X-Gotify-Key", &self.token).json(&body)
+        })
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+
+    #[tokio::test]
+    async fn posts_message_with_app_token() {
+        let server = TestServer::start(vec![(200, "{}")]).await;
+        let gotify = Gotify::new(GotifyConfig {
+            server_url: format!("{}/gotify/", server.url),
+            token: " app-token ".to_string(),
+            priority: 8,
+        })
+        .unwrap();
+        gotify.send(&NotificationMessage::test("Gotify")).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/gotify/message");
+        assert_eq!(request.header("X-Gotify-Key"), Some("app-token"));
+        assert_eq!(
+            request.json(),
+            json!({
+                "title": "叮咚 测试",
+                "message": "这是一条测试消息，说明 Gotify 配置成功！",
+                "priority": 8,
+                "extras": { "client::display": { "contentType": "text/markdown" } },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_unauthorized() {
+        let server = TestServer::start(vec![(401, r#"{"error":"Unauthorized"}"#), (200, "{}")]).await;
+        let gotify = Gotify::new(GotifyConfig { server_url: server.url.clone(), token: "bad".to_string(), priority: 5 }).unwrap();
+        assert!(gotify.send(&NotificationMessage::test("Gotify")).await.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_priority() {
+        let config = GotifyConfig { server_url: "https://push.example.com".to_string(), token: "t".to_string(), priority: 11 };
+        assert!(Gotify::new(config).is_err());
+    }
+}
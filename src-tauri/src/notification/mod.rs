@@ -1,3 +1,24 @@
+pub mod bark;
+pub mod channel;
+pub mod dingtalk;
+pub mod email;
+pub mod feishu;
+pub mod gotify;
+pub mod ntfy;
 pub mod popup;
+pub mod presentation;
+pub mod pushplus;
+pub mod robot;
 pub mod serverchan;
+pub mod telegram;
 pub mod tray;
+pub mod webhook;
+pub mod wecom;
+
+/// 转义 HTML 文本中的特殊字符
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+pub(crate) mod test_server;
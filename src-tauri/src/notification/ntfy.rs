@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+use crate::notification::robot;
+
+const DEFAULT_SERVER: &str = "https://ntfy.sh";
+
+/// ntfy 最多支持的操作按钮数
+const MAX_ACTIONS: usize = 3;
+
+fn default_priority() -> u8 {
+    3
+}
+
+/// ntfy 配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NtfyConfig {
+    pub topic: String,
+    /// 服务器地址（为空时使用 ntfy.sh，可填写自建服务）
+    #[serde(default)]
+    pub server_url: Option<String>,
+    /// 访问令牌（受保护的主题需要）
+    #[serde(default)]
+    pub token: Option<String>,
+    /// 优先级 1-5（默认 3）
+    #[serde(default = "default_priority")]
+    pub priority: u8,
+    /// 标签（可使用表情名称，如 alarm_clock）
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// ntfy 通知渠道
+pub struct Ntfy {
+    url: Url,
+    config: NtfyConfig,
+}
+
+impl Ntfy {
+    pub fn new(config: NtfyConfig) -> AppResult<Self> {
+        if config.topic.trim().is_empty() {
+            return Err(AppError::Validation("ntfy 主题不能为空".to_string()));
+        }
+        if !(1..=5).contains(&config.priority) {
+            return Err(AppError::Validation("ntfy 优先级必须在 1-5 之间".to_string()));
+        }
+        let server = config.server_url.as_deref().map(str::trim).filter(|s| !s.is_empty()).unwrap_or(DEFAULT_SERVER);
+        let url = parse_url(server, "ntfy 服务器")?;
+        Ok(Self { url, config })
+    }
+
+    /// 以 JSON 方式发布到服务器根路径
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let actions: Vec<_> = robot::links(message).iter()
+            .take(MAX_ACTIONS)
+            .map(|link| json!({ "action": "view", "label": link.name, "url": link.url }))
+            .collect();
+
+        json!({
+            "topic": self.config.topic.trim(),
+            "title": message.title,
+            "message": robot::markdown_text(message, false),
+            "priority": self.config.priority,
+            "tags": self.config.tags,
+            "actions": actions,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Ntfy {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = self.body(message);
+        let token = self.config.token.as_deref().filter(|t| !t.is_empty());
+
+        send_with_retry(RetryPolicy::default(), || {
+            let request = client.post(self.url.clone()).json(&body);
+            match token {
+                Some(token) => request.bearer_auth(token),
+                None => request,
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+
+    fn config(server_url: &str, token: Option<&str>, priority: u8) -> NtfyConfig {
+        NtfyConfig {
+            topic: " reminders ".to_string(),
+            server_url: Some(server_url.to_string()),
+            token: token.map(str::to_string),
+            priority,
+            tags: vec!["alarm_clock".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn publishes_json_with_token() {
+        let server = TestServer::start(vec![(200, "{}")]).await;
+        Ntfy::new(config(&server.url, Some("tk_secret"), 4)).unwrap()
+            .send(&NotificationMessage::test("ntfy")).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/");
+        assert_eq!(request.header("Authorization"), Some("Bearer tk_secret"));
+        assert_eq!(
+            request.json(),
+            json!({
+                "topic": "reminders",
+                "title": "叮咚 测试",
+                "message": "这是一条测试消息，说明 ntfy 配置成功！",
+                "priority": 4,
+                "tags": ["alarm_clock"],
+                "actions": [],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn omits_authorization_without_token() {
+        let server = TestServer::start(vec![(200, "{}")]).await;
+        Ntfy::new(config(&server.url, Some(""), 3)).unwrap()
+            .send(&NotificationMessage::test("ntfy")).await.unwrap();
+        assert_eq!(server.requests()[0].header("Authorization"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_priority() {
+        assert!(Ntfy::new(config("https://ntfy.sh", None, 0)).is_err());
+        assert!(Ntfy::new(config("https://ntfy.sh", None, 6)).is_err());
+    }
+}
@@ -1,12 +1,49 @@
 use tauri::{AppHandle, Manager, WebviewWindowBuilder, WebviewUrl, PhysicalPosition};
+use chrono::{DateTime, Duration, Utc};
 use std::sync::Mutex;
 use std::collections::VecDeque;
 
+use crate::error::AppResult;
+use crate::notification::presentation;
+use crate::scheduler::manager;
+use crate::storage::settings_repo;
+
 /// 最大同时显示的弹窗数量
 const MAX_VISIBLE_POPUPS: usize = 5;
 
-/// 等待显示的弹窗队列
-static POPUP_QUEUE: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+/// 等待显示的弹窗
+struct QueuedPopup {
+    reminder_uuid: String,
+    queued_at: DateTime<Utc>,
+    /// 显示后才开始超时检测和循环提醒（勿扰期间暂缓的提醒）
+    arm_follow_ups: bool,
+}
+
+/// 等待显示的弹窗队列（弹窗数量达到上限或处于演示模式时暂存）
+static POPUP_QUEUE: Mutex<VecDeque<QueuedPopup>> = Mutex::new(VecDeque::new());
+
+/// 重新检测演示模式（会执行外部命令，需在后台线程调用；未开启暂缓弹窗时不检测）
+pub fn refresh_presentation() {
+    if settings_repo::get_all().unwrap_or_default().defer_popups_when_presenting {
+        presentation::refresh();
+    } else {
+        presentation::reset();
+    }
+}
+
+/// 是否因演示模式暂缓弹窗，返回 (是否在演示, 最长暂缓时间)
+///
+/// 只读取调度器缓存的检测结果，可在窗口事件等主线程回调中调用。
+fn presentation_hold() -> (bool, Option<Duration>) {
+    let settings = settings_repo::get_all().unwrap_or_default();
+    if !settings.defer_popups_when_presenting {
+        return (false, None);
+    }
+    let max_defer = Some(settings.max_popup_defer_minutes)
+        .filter(|m| *m > 0)
+        .map(|m| Duration::minutes(m as i64));
+    (presentation::is_presenting(), max_defer)
+}
 
 /// 获取当前已有的弹窗数量
 fn get_popup_count(app: &AppHandle) -> usize {
@@ -16,44 +53,82 @@ fn get_popup_count(app: &AppHandle) -> usize {
         .count()
 }
 
-/// 尝试从队列中显示下一个弹窗
-pub fn try_show_next_popup(app: &AppHandle) {
-    let next_uuid = {
+/// 尝试从队列中显示下一个弹窗，返回是否显示了弹窗
+///
+/// 演示模式下暂不显示，除非已暂缓超过最长时间。
+pub fn try_show_next_popup(app: &AppHandle) -> bool {
+    if POPUP_QUEUE.lock().unwrap().is_empty() {
+        return false;
+    }
+    let (presenting, max_defer) = presentation_hold();
+
+    let next = {
         let mut queue = POPUP_QUEUE.lock().unwrap();
-        if get_popup_count(app) < MAX_VISIBLE_POPUPS {
+        let overdue = queue.front().map_or(false, |item| {
+            max_defer.map_or(false, |max| Utc::now() - item.queued_at >= max)
+        });
+        if get_popup_count(app) < MAX_VISIBLE_POPUPS && (!presenting || overdue) {
             queue.pop_front()
         } else {
             None
         }
     };
 
-    if let Some(uuid) = next_uuid {
-        let _ = show_reminder_popup_internal(app, &uuid, false);
+    match next {
+        Some(item) => {
+            let _ = show_reminder_popup_internal(app, &item.reminder_uuid, false, item.arm_follow_ups);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 显示队列中所有可以显示的弹窗（调度器定期调用，退出演示模式后补上暂缓的弹窗）
+pub fn flush_popup_queue(app: &AppHandle) {
+    for _ in 0..MAX_VISIBLE_POPUPS {
+        if !try_show_next_popup(app) {
+            break;
+        }
     }
 }
 
-pub fn show_reminder_popup(app: &AppHandle, reminder_uuid: &str) -> Result<(), Box<dyn std::error::Error>> {
-    show_reminder_popup_internal(app, reminder_uuid, true)
+pub fn show_reminder_popup(app: &AppHandle, reminder_uuid: &str) -> AppResult<()> {
+    show_reminder_popup_internal(app, reminder_uuid, true, false)
 }
 
-fn show_reminder_popup_internal(app: &AppHandle, reminder_uuid: &str, allow_queue: bool) -> Result<(), Box<dyn std::error::Error>> {
+fn show_reminder_popup_internal(
+    app: &AppHandle,
+    reminder_uuid: &str,
+    allow_queue: bool,
+    arm_follow_ups: bool,
+) -> AppResult<()> {
     let popup_label = format!("popup-{}", reminder_uuid);
 
     // 检查弹窗是否已存在，如果存在则聚焦
     if let Some(existing) = app.get_webview_window(&popup_label) {
         let _ = existing.set_focus();
+        if arm_follow_ups {
+            tauri::async_runtime::spawn(manager::arm_follow_ups(reminder_uuid.to_string()));
+        }
         return Ok(());
     }
 
     // 获取当前弹窗数量
     let current_count = get_popup_count(app);
 
-    // 如果超过最大数量，加入队列等待
-    if allow_queue && current_count >= MAX_VISIBLE_POPUPS {
+    // 如果超过最大数量或正在演示，加入队列等待
+    if allow_queue && (current_count >= MAX_VISIBLE_POPUPS || presentation_hold().0) {
         let mut queue = POPUP_QUEUE.lock().unwrap();
         // 避免重复加入队列
-        if !queue.contains(&reminder_uuid.to_string()) {
-            queue.push_back(reminder_uuid.to_string());
+        if let Some(item) = queue.iter_mut().find(|item| item.reminder_uuid == reminder_uuid) {
+            item.arm_follow_ups |= arm_follow_ups;
+        } else {
+            println!("[弹窗] 暂缓显示: {}", reminder_uuid);
+            queue.push_back(QueuedPopup {
+                reminder_uuid: reminder_uuid.to_string(),
+                queued_at: Utc::now(),
+                arm_follow_ups,
+            });
         }
         return Ok(());
     }
@@ -123,18 +198,43 @@ fn show_reminder_popup_internal(app: &AppHandle, reminder_uuid: &str, allow_queu
     popup.show()?;
     popup.set_focus()?;
 
+    if arm_follow_ups {
+        tauri::async_runtime::spawn(manager::arm_follow_ups(reminder_uuid.to_string()));
+    }
+
     Ok(())
 }
 
-pub fn close_reminder_popup(app: &AppHandle, reminder_uuid: &str) -> Result<(), Box<dyn std::error::Error>> {
+pub fn close_reminder_popup(app: &AppHandle, reminder_uuid: &str) -> AppResult<()> {
     let popup_label = format!("popup-{}", reminder_uuid);
 
     if let Some(window) = app.get_webview_window(&popup_label) {
         window.close()?;
     }
 
+    // 已在主窗口处理的提醒不再弹出
+    POPUP_QUEUE.lock().unwrap().retain(|item| item.reminder_uuid != reminder_uuid);
+
     // 尝试显示队列中的下一个弹窗
     try_show_next_popup(app);
 
     Ok(())
 }
+
+/// 依次显示多个提醒的弹窗（错过的提醒汇总补发），超出同时显示上限的进入队列等待
+pub fn show_reminder_popups(app: &AppHandle, reminder_uuids: &[String]) {
+    for uuid in reminder_uuids {
+        if let Err(e) = show_reminder_popup(app, uuid) {
+            eprintln!("[弹窗] 创建弹窗失败: {} ({})", uuid, e);
+        }
+    }
+}
+
+/// 依次显示勿扰期间暂缓的提醒，弹窗真正显示后才开始超时检测和循环提醒
+pub fn show_deferred_popups(app: &AppHandle, reminder_uuids: &[String]) {
+    for uuid in reminder_uuids {
+        if let Err(e) = show_reminder_popup_internal(app, uuid, true, true) {
+            eprintln!("[弹窗] 创建弹窗失败: {} ({})", uuid, e);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 演示模式检测（全屏应用、屏幕共享等），处于演示模式时暂缓弹窗
+pub trait PresentationDetector: Send + Sync {
+    fn is_presenting(&self) -> bool;
+}
+
+/// 不支持检测的平台，始终视为未演示
+pub struct NoopDetector;
+
+impl PresentationDetector for NoopDetector {
+    fn is_presenting(&self) -> bool {
+        false
+    }
+}
+
+/// 执行外部命令并返回标准输出（失败返回 None），便于检测逻辑替换为模拟实现
+pub trait CommandProvider: Send + Sync {
+    fn run(&self, program: &str, args: &[&str]) -> Option<String>;
+    fn env(&self, key: &str) -> Option<String>;
+}
+
+/// 调用系统命令
+pub struct SystemCommands;
+
+impl CommandProvider for SystemCommands {
+    fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+        let output = std::process::Command::new(program).args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        String::from_utf8(output.stdout).ok()
+    }
+
+    fn env(&self, key: &str) -> Option<String> {
+        std::env::var(key).ok().filter(|v| !v.is_empty())
+    }
+}
+
+/// GNOME 会话的空闲抑制标志（全屏播放、演示时应用会抑制空闲）
+const GNOME_INHIBIT_IDLE: &str = "8";
+
+/// Linux 检测：
+/// - X11（含 XWayland 窗口）：通过 xprop 查看当前活动窗口是否带 `_NET_WM_STATE_FULLSCREEN`
+/// - Wayland：原生窗口无法读取全屏状态，退而检查 GNOME 会话是否被抑制空闲
+pub struct LinuxDetector<P: CommandProvider> {
+    provider: P,
+}
+
+impl<P: CommandProvider> LinuxDetector<P> {
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    /// 活动窗口是否全屏（无法获取时返回 None）
+    fn active_window_fullscreen(&self) -> Option<bool> {
+        self.provider.env("DISPLAY")?;
+        let active = self.provider.run("xprop", &["-root", "_NET_ACTIVE_WINDOW"])?;
+        // 输出形如 "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007"
+        let window_id = active.split_whitespace().last()?;
+        if !window_id.starts_with("0x") || window_id == "0x0" {
+            return None;
+        }
+        let state = self.provider.run("xprop", &["-id", window_id, "_NET_WM_STATE"])?;
+        Some(state.contains("_NET_WM_STATE_FULLSCREEN"))
+    }
+
+    /// GNOME 会话是否被抑制空闲（非 GNOME 或调用失败时返回 None）
+    fn session_idle_inhibited(&self) -> Option<bool> {
+        let output = self.provider.run("gdbus", &[
+            "call", "--session",
+            "--dest", "org.gnome.SessionManager",
+            "--object-path", "/org/gnome/SessionManager",
+            "--method", "org.gnome.SessionManager.IsInhibited", GNOME_INHIBIT_IDLE,
+        ])?;
+        // 输出形如 "(true,)"
+        Some(output.contains("true"))
+    }
+}
+
+impl<P: CommandProvider> PresentationDetector for LinuxDetector<P> {
+    fn is_presenting(&self) -> bool {
+        if self.active_window_fullscreen() == Some(true) {
+            return true;
+        }
+        if self.provider.env("WAYLAND_DISPLAY").is_some() {
+            return self.session_idle_inhibited().unwrap_or(false);
+        }
+        false
+    }
+}
+
+/// 当前平台的检测实现
+static DETECTOR: Lazy<Box<dyn PresentationDetector>> = Lazy::new(|| {
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(LinuxDetector::new(SystemCommands))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Box::new(NoopDetector)
+    }
+});
+
+/// 最近一次检测的结果
+static PRESENTING: AtomicBool = AtomicBool::new(false);
+
+/// 重新检测是否处于演示模式并缓存结果（会执行外部命令，由调度器在后台线程调用）
+pub fn refresh() -> bool {
+    let presenting = DETECTOR.is_presenting();
+    PRESENTING.store(presenting, Ordering::Relaxed);
+    presenting
+}
+
+/// 清除缓存的检测结果（关闭暂缓弹窗时调用）
+pub fn reset() {
+    PRESENTING.store(false, Ordering::Relaxed);
+}
+
+/// 当前是否处于演示模式（读取缓存，不执行外部命令，可在主线程调用）
+pub fn is_presenting() -> bool {
+    PRESENTING.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// 模拟的命令输出与环境变量
+    #[derive(Default)]
+    struct FakeCommands {
+        outputs: HashMap<String, String>,
+        env: HashMap<String, String>,
+    }
+
+    impl FakeCommands {
+        fn output(mut self, command: &str, output: &str) -> Self {
+            self.outputs.insert(command.to_string(), output.to_string());
+            self
+        }
+
+        fn env(mut self, key: &str, value: &str) -> Self {
+            self.env.insert(key.to_string(), value.to_string());
+            self
+        }
+    }
+
+    impl CommandProvider for FakeCommands {
+        fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+            let command = std::iter::once(program).chain(args.iter().copied()).collect::<Vec<_>>().join(" ");
+            self.outputs.get(&command).cloned()
+        }
+
+        fn env(&self, key: &str) -> Option<String> {
+            self.env.get(key).cloned()
+        }
+    }
+
+    const ACTIVE_WINDOW: &str = "xprop -root _NET_ACTIVE_WINDOW";
+    const WINDOW_STATE: &str = "xprop -id 0x3a00007 _NET_WM_STATE";
+    const GNOME_INHIBITED: &str = "gdbus call --session --dest org.gnome.SessionManager \
+        --object-path /org/gnome/SessionManager --method org.gnome.SessionManager.IsInhibited 8";
+
+    #[test]
+    fn x11_fullscreen_window_is_presenting() {
+        let commands = FakeCommands::default()
+            .env("DISPLAY", ":0")
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n")
+            .output(WINDOW_STATE, "_NET_WM_STATE(ATOM) = _NET_WM_STATE_FULLSCREEN\n");
+        assert!(LinuxDetector::new(commands).is_presenting());
+    }
+
+    #[test]
+    fn x11_normal_window_is_not_presenting() {
+        let commands = FakeCommands::default()
+            .env("DISPLAY", ":0")
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n")
+            .output(WINDOW_STATE, "_NET_WM_STATE(ATOM) = _NET_WM_STATE_MAXIMIZED_VERT\n");
+        assert!(!LinuxDetector::new(commands).is_presenting());
+    }
+
+    #[test]
+    fn missing_active_window_is_not_presenting() {
+        let no_window = FakeCommands::default()
+            .env("DISPLAY", ":0")
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x0\n")
+            .output("xprop -id 0x0 _NET_WM_STATE", "_NET_WM_STATE(ATOM) = _NET_WM_STATE_FULLSCREEN\n");
+        assert!(!LinuxDetector::new(no_window).is_presenting());
+
+        let not_found = FakeCommands::default()
+            .env("DISPLAY", ":0")
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW:  not found.\n");
+        assert!(!LinuxDetector::new(not_found).is_presenting());
+
+        let xprop_failed = FakeCommands::default().env("DISPLAY", ":0");
+        assert!(!LinuxDetector::new(xprop_failed).is_presenting());
+    }
+
+    #[test]
+    fn display_unset_skips_xprop() {
+        let commands = FakeCommands::default()
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n")
+            .output(WINDOW_STATE, "_NET_WM_STATE(ATOM) = _NET_WM_STATE_FULLSCREEN\n");
+        assert!(!LinuxDetector::new(commands).is_presenting());
+    }
+
+    #[test]
+    fn wayland_uses_gnome_idle_inhibition() {
+        let inhibited = FakeCommands::default()
+            .env("WAYLAND_DISPLAY", "wayland-0")
+            .output(GNOME_INHIBITED, "(true,)\n");
+        assert!(LinuxDetector::new(inhibited).is_presenting());
+
+        let not_inhibited = FakeCommands::default()
+            .env("WAYLAND_DISPLAY", "wayland-0")
+            .output(GNOME_INHIBITED, "(false,)\n");
+        assert!(!LinuxDetector::new(not_inhibited).is_presenting());
+
+        let not_gnome = FakeCommands::default().env("WAYLAND_DISPLAY", "wayland-0");
+        assert!(!LinuxDetector::new(not_gnome).is_presenting());
+    }
+
+    #[test]
+    fn wayland_fullscreen_xwayland_window_is_presenting() {
+        let commands = FakeCommands::default()
+            .env("WAYLAND_DISPLAY", "wayland-0")
+            .env("DISPLAY", ":0")
+            .output(ACTIVE_WINDOW, "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n")
+            .output(WINDOW_STATE, "_NET_WM_STATE(ATOM) = _NET_WM_STATE_FULLSCREEN\n")
+            .output(GNOME_INHIBITED, "(false,)\n");
+        assert!(LinuxDetector::new(commands).is_presenting());
+    }
+}
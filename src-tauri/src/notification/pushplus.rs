@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+use crate::notification::robot;
+
+const DEFAULT_SERVER: &str = "https://www.pushplus.plus";
+
+/// PushPlus 配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushPlusConfig {
+    pub token: String,
+    /// 群组编码（一对多推送，为空时只发给自己）
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// 发送渠道（wechat、webhook、mail 等，为空时为微信公众号）
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// 服务器地址（为空时使用官方地址）
+    #[serde(default)]
+    pub server_url: Option<String>,
+}
+
+/// PushPlus 通知渠道
+pub struct PushPlus {
+    url: Url,
+    config: PushPlusConfig,
+}
+
+#[derive(Deserialize)]
+struct PushPlusResponse {
+    code: i64,
+    msg: Option<String>,
+}
+
+impl PushPlus {
+    pub fn new(config: PushPlusConfig) -> AppResult<Self> {
+        if config.token.trim().is_empty() {
+            return Err(AppError::Validation("PushPlus Token 不能为空".to_string()));
+        }
+        let server = config.server_url.as_deref().map(str::trim).filter(|s| !s.is_empty()).unwrap_or(DEFAULT_SERVER);
+        let url = parse_url(&format!("{}/send", server.trim_end_matches('/')), "PushPlus 服务")?;
+        Ok(Self { url, config })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let mut body = json!({
+            "token": self.config.token.trim(),
+            "title": message.title,
+            "content": robot::markdown_text(message, true),
+            "template": "markdown",
+        });
+        if let Some(topic) = self.config.topic.as_deref().filter(|t| !t.is_empty()) {
+            body["topic"] = json!(topic);
+        }
+        if let Some(channel) = self.config.channel.as_deref().filter(|c| !c.is_empty()) {
+            body["channel"] = json!(channel);
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for PushPlus {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = self.body(message);
+        let response = send_with_retry(RetryPolicy::default(), || client.post(self.url.clone()).json(&body)).await?;
+
+        let result: PushPlusResponse = response.json().await?;
+        if result.code != 200 {
+            return Err(AppError::Notification(format!(
+                "PushPlus 返回错误 {}: {}",
+                result.code,
+                result.msg.unwrap_or_default()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+
+    fn pushplus(server_url: &str, topic: Option<&str>) -> PushPlus {
+        PushPlus::new(PushPlusConfig {
+            token: " token ".to_string(),
+            topic: topic.map(str::to_string),
+            channel: Some("mail".to_string()),
+            server_url: Some(server_url.to_string()),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_markdown_message() {
+        let server = TestServer::start(vec![(200, r#"{"code":200,"msg":"请求成功"}"#)]).await;
+        pushplus(&server.url, Some("team")).send(&NotificationMessage::test("PushPlus")).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/send");
+        assert_eq!(
+            request.json(),
+            json!({
+                "token": "token",
+                "title": "叮咚 测试",
+                "content": "这是一条测试消息，说明 PushPlus 配置成功！",
+                "template": "markdown",
+                "topic": "team",
+                "channel": "mail",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_api_error() {
+        let server = TestServer::start(vec![(200, r#"{"code":903,"msg":"无效的用户token"}"#)]).await;
+        let result = pushplus(&server.url, None).send(&NotificationMessage::test("PushPlus")).await;
+        match result {
+            Err(AppError::Notification(message)) => assert!(message.contains("无效的用户token"), "{}", message),
+            other => panic!("应返回通知错误: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::{Response, Url};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::error::{AppError, AppResult};
+use crate::models::reminder::ReminderLink;
+use crate::notification::channel::{http_client, send_with_retry, NotificationMessage, RetryPolicy};
+
+/// 群机器人消息格式
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RobotFormat {
+    /// Markdown 消息，链接以文字链接展示
+    #[default]
+    Markdown,
+    /// 卡片消息，链接以按钮展示
+    Card,
+}
+
+/// HMAC-SHA256 签名并 Base64 编码
+pub fn sign(key: &[u8], data: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC 可接受任意长度的密钥");
+    mac.update(data);
+    BASE64.encode(mac.finalize().into_bytes())
+}
+
+/// 消息中提醒的链接
+pub fn links(message: &NotificationMessage) -> &[ReminderLink] {
+    message.reminder.as_ref()
+        .and_then(|r| r.links.as_deref())
+        .unwrap_or_default()
+}
+
+/// 提醒内容（Markdown），`with_links` 为 true 时在末尾附上文字链接
+pub fn markdown_text(message: &NotificationMessage, with_links: bool) -> String {
+    let mut text = String::new();
+    if !message.content.trim().is_empty() {
+        text.push_str(message.content.trim());
+    }
+    if with_links {
+        for link in links(message) {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&format!("[{}]({})", link.name, link.url));
+        }
+    }
+    text
+}
+
+/// 机器人接口返回的业务结果（钉钉、企业微信为 errcode/errmsg，飞书为 code/msg）
+#[derive(Deserialize)]
+struct RobotResponse {
+    errcode: Option<i64>,
+    errmsg: Option<String>,
+    code: Option<i64>,
+    msg: Option<String>,
+}
+
+/// 检查机器人接口返回的错误码（HTTP 成功但错误码非 0 时表示发送失败）
+async fn check_response(response: Response, name: &str) -> AppResult<()> {
+    let body: RobotResponse = response.json().await?;
+    match body.errcode.or(body.code) {
+        Some(0) | None => Ok(()),
+        Some(code) => Err(AppError::Notification(format!(
+            "{}返回错误 {}: {}",
+            name,
+            code,
+            body.errmsg.or(body.msg).unwrap_or_default()
+        ))),
+    }
+}
+
+/// 向群机器人发送 JSON 消息，失败时按默认策略重试
+pub async fn post(url: Url, body: &serde_json::Value, name: &str) -> AppResult<()> {
+    post_with(url, body, name, RetryPolicy::default()).await
+}
+
+/// 向群机器人发送 JSON 消息，失败时按指定策略重试
+pub async fn post_with(url: Url, body: &serde_json::Value, name: &str, policy: RetryPolicy) -> AppResult<()> {
+    let client = http_client()?;
+    let response = send_with_retry(policy, || client.post(url.clone()).json(body)).await?;
+    check_response(response, name).await
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::models::reminder::Reminder;
+    use crate::notification::test_server::TestServer;
+    use std::time::Duration;
+
+    /// 测试用的重试策略（不等待）
+    pub(crate) const FAST_RETRY: RetryPolicy = RetryPolicy { max_attempts: 3, base_delay: Duration::from_millis(1) };
+
+    /// 带链接的提醒触发消息
+    pub(crate) fn message_with_links(content: &str, links: &[(&str, &str)]) -> NotificationMessage {
+        let links: Vec<_> = links.iter().map(|(name, url)| serde_json::json!({ "name": name, "url": url })).collect();
+        let reminder: Reminder = serde_json::from_value(serde_json::json!({
+            "uuid": "robot-test", "title": "站会", "content": content, "links": links,
+            "remindTime": "09:00", "remindType": "daily",
+            "isCritical": false, "skipHolidays": false, "isEnabled": true, "repeatOnClose": false, "isLoop": false,
+            "triggerChannels": [], "completeChannels": [], "timeoutChannels": [], "sortOrder": 0,
+            "createdAt": "2026-01-01T00:00:00Z", "updatedAt": "2026-01-01T00:00:00Z",
+            "version": 1, "syncStatus": "pending"
+        }))
+        .unwrap();
+        NotificationMessage::trigger(&reminder)
+    }
+
+    #[test]
+    fn signs_with_hmac_sha256() {
+        // RFC 4231 测试用例 2
+        assert_eq!(sign(b"Jefe", b"what do ya want for nothing?"), "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM=");
+    }
+
+    #[test]
+    fn markdown_text_appends_links() {
+        let message = message_with_links(" 10 点开会 ", &[("会议室", "https://example.com/room"), ("议程", "https://example.com/agenda")]);
+        assert_eq!(
+            markdown_text(&message, true),
+            "10 点开会\n\n[会议室](https://example.com/room)\n\n[议程](https://example.com/agenda)"
+        );
+        assert_eq!(markdown_text(&message, false), "10 点开会");
+        assert_eq!(markdown_text(&message_with_links("", &[("议程", "https://example.com/agenda")]), true), "[议程](https://example.com/agenda)");
+    }
+
+    #[tokio::test]
+    async fn reports_error_codes_from_either_format() {
+        let server = TestServer::start(vec![
+            (200, r#"{"errcode":0,"errmsg":"ok"}"#),
+            (200, r#"{"code":19021,"msg":"sign match fail"}"#),
+            (200, r#"{"errcode":310000,"errmsg":"keywords not in content"}"#),
+        ])
+        .await;
+        let url: Url = format!("{}/robot", server.url).parse().unwrap();
+        let body = serde_json::json!({ "msgtype": "text" });
+
+        post_with(url.clone(), &body, "机器人", FAST_RETRY).await.unwrap();
+        for expected in ["19021: sign match fail", "310000: keywords not in content"] {
+            match post_with(url.clone(), &body, "机器人", FAST_RETRY).await {
+                Err(AppError::Notification(message)) => assert!(message.contains(expected), "{}", message),
+                other => panic!("应返回通知错误: {:?}", other),
+            }
+        }
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors() {
+        let server = TestServer::start(vec![(502, ""), (200, r#"{"errcode":0}"#)]).await;
+        let url: Url = format!("{}/robot", server.url).parse().unwrap();
+        post_with(url, &serde_json::json!({}), "机器人", FAST_RETRY).await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -1,51 +1,60 @@
-use reqwest::Client;
-use serde::Serialize;
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+
+/// Server酱渠道配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerChanConfig {
+    /// 自定义域名（为空时使用 sctapi.ftqq.com）
+    #[serde(default)]
+    pub domain: Option<String>,
+    pub sendkey: String,
+}
 
-#[derive(Serialize)]
-struct ServerChanMessage {
-    title: String,
-    desp: String,
+/// Server酱通知渠道
+pub struct ServerChan {
+    url: Url,
 }
 
-pub async fn send_notification(
-    domain: Option<&str>,
-    sendkey: &str,
-    title: &str,
-    content: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let client = Client::new();
-
-    // 处理域名：去掉协议前缀
-    let base_url = domain
-        .filter(|d| !d.is_empty())
-        .map(|d| d.trim_start_matches("https://").trim_start_matches("http://"))
-        .unwrap_or("sctapi.ftqq.com");
-
-    // 根据域名选择路径格式
-    let url = if base_url.contains("push.ft07.com") {
-        format!("https://{}/send/{}.send", base_url, sendkey)
-    } else {
-        format!("https://{}/{}.send", base_url, sendkey)
-    };
-
-    let message = ServerChanMessage {
-        title: title.to_string(),
-        desp: content.to_string(),
-    };
-
-    let response = client.post(&url)
-        .json(&message)
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        return Err(format!("Server酱请求失败: {}", response.status()).into());
+impl ServerChan {
+    pub fn new(config: ServerChanConfig) -> AppResult<Self> {
+        let sendkey = config.sendkey.trim();
+        if sendkey.is_empty() {
+            return Err(AppError::Validation("Server酱 SendKey 不能为空".to_string()));
+        }
+
+        // 处理域名：去掉协议前缀
+        let base_url = config.domain.as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(|d| d.trim_start_matches("https://").trim_start_matches("http://").trim_end_matches('/'))
+            .unwrap_or("sctapi.ftqq.com");
+
+        // 根据域名选择路径格式
+        let url = if base_url.contains("push.ft07.com") {
+            format!("https://{}/send/{}.send", base_url, sendkey)
+        } else {
+            format!("https://{}/{}.send", base_url, sendkey)
+        };
+        Ok(Self { url: parse_url(&url, "Server酱 ")? })
     }
+}
 
-    Ok(())
+#[derive(Serialize)]
+struct ServerChanMessage<'a> {
+    title: &'a str,
+    desp: &'a str,
 }
 
-pub async fn test_connection(domain: Option<&str>, sendkey: &str) -> Result<bool, Box<dyn std::error::Error>> {
-    send_notification(domain, sendkey, "叮咚 测试", "这是一条测试消息，说明 Server酱 配置成功！").await?;
-    Ok(true)
+#[async_trait]
+impl NotificationChannel for ServerChan {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = ServerChanMessage { title: &message.title, desp: &message.content };
+        send_with_retry(RetryPolicy::default(), || client.post(self.url.clone()).json(&body)).await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{http_client, parse_url, send_with_retry, NotificationChannel, NotificationMessage, RetryPolicy};
+use crate::notification::{escape_html, robot};
+
+const DEFAULT_SERVER: &str = "https://api.telegram.org";
+
+/// Telegram 机器人配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TelegramConfig {
+    pub bot_token: String,
+    /// 接收消息的用户、群组或频道 ID
+    pub chat_id: String,
+    /// Bot API 地址（为空时使用官方地址，可填写自建的反向代理）
+    #[serde(default)]
+    pub server_url: Option<String>,
+    /// 静默发送（不响铃）
+    #[serde(default)]
+    pub silent: bool,
+}
+
+/// Telegram 通知渠道
+pub struct Telegram {
+    url: Url,
+    chat_id: String,
+    silent: bool,
+}
+
+#[derive(Deserialize)]
+struct TelegramResponse {
+    ok: bool,
+    description: Option<String>,
+}
+
+impl Telegram {
+    pub fn new(config: TelegramConfig) -> AppResult<Self> {
+        let token = config.bot_token.trim();
+        if token.is_empty() || config.chat_id.trim().is_empty() {
+            return Err(AppError::Validation("Telegram Bot Token 和 Chat ID 不能为空".to_string()));
+        }
+        let server = config.server_url.as_deref().map(str::trim).filter(|s| !s.is_empty()).unwrap_or(DEFAULT_SERVER);
+        let url = parse_url(&format!("{}/bot{}/sendMessage", server.trim_end_matches('/'), token), "Telegram 服务")?;
+        Ok(Self { url, chat_id: config.chat_id.trim().to_string(), silent: config.silent })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let mut text = format!("<b>{}</b>", escape_html(&message.title));
+        if !message.content.trim().is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&escape_html(message.content.trim()));
+        }
+
+        let mut body = json!({
+            "chat_id": self.chat_id,
+            "text": text,
+            "parse_mode": "HTML",
+            "disable_notification": self.silent,
+        });
+        let buttons: Vec<_> = robot::links(message).iter()
+            .map(|link| vec![json!({ "text": link.name, "url": link.url })])
+            .collect();
+        if !buttons.is_empty() {
+            body["reply_markup"] = json!({ "inline_keyboard": buttons });
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Telegram {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        let client = http_client()?;
+        let body = self.body(message);
+        let response = send_with_retry(RetryPolicy::default(), || client.post(self.url.clone()).json(&body)).await?;
+
+        let result: TelegramResponse = response.json().await?;
+        if !result.ok {
+            return Err(AppError::Notification(format!("Telegram 返回错误: {}", result.description.unwrap_or_default())));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+
+    fn telegram(server_url: &str, silent: bool) -> Telegram {
+        Telegram::new(TelegramConfig {
+            bot_token: " 123:abc ".to_string(),
+            chat_id: "-100200".to_string(),
+            server_url: Some(format!("{}/", server_url)),
+            silent,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_html_message_to_bot_api() {
+        let server = TestServer::start(vec![(200, r#"{"ok":true}"#)]).await;
+        telegram(&server.url, true).send(&NotificationMessage::test("Telegram")).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/bot123:abc/sendMessage");
+        assert_eq!(
+            request.json(),
+            json!({
+                "chat_id": "-100200",
+                "text": "<b>叮咚 测试</b>\n\n这是一条测试消息，说明 Telegram 配置成功！",
+                "parse_mode": "HTML",
+                "disable_notification": true,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_api_error() {
+        let server = TestServer::start(vec![(200, r#"{"ok":false,"description":"chat not found"}"#)]).await;
+        let result = telegram(&server.url, false).send(&NotificationMessage::test("Telegram")).await;
+        match result {
+            Err(AppError::Notification(message)) => assert!(message.contains("chat not found"), "{}", message),
+            other => panic!("应返回通知错误: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn requires_token_and_chat_id() {
+        let config = TelegramConfig { bot_token: "123:abc".to_string(), chat_id: " ".to_string(), server_url: None, silent: false };
+        assert!(Telegram::new(config).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+//! 测试用的本地服务：HTTP 服务按顺序返回预设的响应，SMTP 服务接收所有邮件，均记录收到的内容
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// 收到的请求
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    /// 路径和查询参数
+    pub path: String,
+    /// 请求头（名称为小写）
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(&self.body).expect("请求体不是 JSON")
+    }
+}
+
+/// 已启动的服务
+pub struct TestServer {
+    /// 服务地址，如 `http://127.0.0.1:12345`
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl TestServer {
+    /// 启动服务，依次用 `responses` 中的 (状态码, 响应体) 响应每个请求
+    pub async fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = requests.clone();
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    break;
+                };
+                if let Some(request) = read_request(&mut stream).await {
+                    received.lock().unwrap().push(request);
+                }
+                let response = format!(
+                    "HTTP/1.1 {} Test\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// 目前收到的所有请求
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// 读取一个完整的请求（按 content-length 读取请求体）
+async fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut buf).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buf[..n]);
+        if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let length: usize = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let mut body = data[header_end + 4..].to_vec();
+    while body.len() < length {
+        let n = stream.read(&mut buf).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&buf[..n]);
+    }
+
+    Some(Request { method, path, headers, body: String::from_utf8_lossy(&body).to_string() })
+}
+
+/// 收到的邮件
+#[derive(Debug, Clone, Default)]
+pub struct Mail {
+    pub from: String,
+    pub to: Vec<String>,
+    /// DATA 部分的原始内容（邮件头和正文）
+    pub data: String,
+}
+
+impl Mail {
+    /// 邮件头的值（展开折行，解码 `=?utf-8?b?...?=` 形式的编码字）
+    pub fn header(&self, name: &str) -> Option<String> {
+        let head = self.data.split("\r\n\r\n").next()?.replace("\r\n ", " ");
+        let prefix = format!("{}: ", name);
+        let mut rest = head.split("\r\n").find_map(|line| line.strip_prefix(&prefix))?;
+
+        let mut decoded = Vec::new();
+        let mut after_word = false;
+        while let Some(start) = rest.find("=?") {
+            // 相邻编码字之间的空白不属于内容
+            if !(after_word && rest[..start].trim().is_empty()) {
+                decoded.extend_from_slice(&rest.as_bytes()[..start]);
+            }
+            let mut parts = rest[start + 2..].splitn(3, '?');
+            let (_charset, encoding, tail) = (parts.next()?, parts.next()?, parts.next()?);
+            let end = tail.find("?=")?;
+            if encoding.eq_ignore_ascii_case("b") {
+                decoded.extend(BASE64.decode(&tail[..end]).ok()?);
+            } else {
+                decoded.extend(decode_quoted_printable(&tail[..end].replace('_', " ")));
+            }
+            rest = &tail[end + 2..];
+            after_word = true;
+        }
+        decoded.extend_from_slice(rest.as_bytes());
+        String::from_utf8(decoded).ok()
+    }
+
+    /// 解码指定类型（如 `text/plain`）的正文，换行统一为 `\n`
+    pub fn part(&self, content_type: &str) -> Option<String> {
+        let start = self.data.find(&format!("Content-Type: {}", content_type))?;
+        let part = &self.data[start..];
+        let (headers, body) = part.split_once("\r\n\r\n")?;
+        let body = body.split("\r\n--").next()?;
+
+        let bytes = if headers.contains("Content-Transfer-Encoding: base64") {
+            BASE64.decode(body.replace("\r\n", "")).ok()?
+        } else if headers.contains("Content-Transfer-Encoding: quoted-printable") {
+            decode_quoted_printable(body)
+        } else {
+            body.as_bytes().to_vec()
+        };
+        Some(String::from_utf8(bytes).ok()?.replace("\r\n", "\n"))
+    }
+}
+
+/// 解码 quoted-printable 编码的正文
+fn decode_quoted_printable(body: &str) -> Vec<u8> {
+    let body = body.replace("=\r\n", "");
+    let bytes = body.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'=', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    decoded
+}
+
+/// 接收所有邮件的 SMTP 服务（不加密、不校验登录）
+pub struct SmtpSink {
+    pub port: u16,
+    mails: Arc<Mutex<Vec<Mail>>>,
+}
+
+impl SmtpSink {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mails = Arc::new(Mutex::new(Vec::new()));
+
+        let received = mails.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let received = received.clone();
+                tokio::spawn(async move {
+                    let _ = serve_smtp(stream, received).await;
+                });
+            }
+        });
+
+        Self { port, mails }
+    }
+
+    /// 目前收到的所有邮件
+    pub fn mails(&self) -> Vec<Mail> {
+        self.mails.lock().unwrap().clone()
+    }
+}
+
+/// 处理一个 SMTP 会话
+async fn serve_smtp(stream: TcpStream, mails: Arc<Mutex<Vec<Mail>>>) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    writer.write_all(b"220 sink ESMTP\r\n").await?;
+
+    let mut mail = Mail::default();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(());
+        }
+        let command = line.trim_end().to_uppercase();
+        let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+            b"250 sink\r\n"
+        } else if command.starts_with("MAIL FROM:") {
+            mail.from = line.trim_end()[10..].trim().to_string();
+            b"250 ok\r\n"
+        } else if command.starts_with("RCPT TO:") {
+            mail.to.push(line.trim_end()[8..].trim().to_string());
+            b"250 ok\r\n"
+        } else if command == "DATA" {
+            writer.write_all(b"354 go ahead\r\n").await?;
+            loop {
+                line.clear();
+                if reader.read_line(&mut line).await? == 0 {
+                    return Ok(());
+                }
+                if line == ".\r\n" {
+                    break;
+                }
+                mail.data.push_str(line.strip_prefix('.').unwrap_or(&line));
+            }
+            mails.lock().unwrap().push(std::mem::take(&mut mail));
+            b"250 queued\r\n"
+        } else if command == "QUIT" {
+            writer.write_all(b"221 bye\r\n").await?;
+            return Ok(());
+        } else {
+            b"250 ok\r\n"
+        };
+        writer.write_all(reply).await?;
+    }
+}
@@ -1,9 +1,12 @@
-use tauri::{AppHandle, Manager, image::Image, tray::TrayIconId};
+use tauri::{AppHandle, Manager, Wry, image::Image, menu::CheckMenuItem, tray::TrayIconId};
 use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::time::{interval, Duration};
 
 static IS_FLASHING: AtomicBool = AtomicBool::new(false);
 
+/// 托盘菜单中的勿扰模式开关（托盘创建时注册为应用状态）
+pub struct DndMenuItem(pub CheckMenuItem<Wry>);
+
 /// 创建缩小版图标（将原图缩小并居中显示）
 fn create_scaled_icon(original: &Image<'_>, scale: f32) -> Image<'static> {
     let orig_width = original.width() as usize;
@@ -104,3 +107,18 @@ pub async fn start_tray_flash(app: &AppHandle) {
 pub fn stop_tray_flash() {
     IS_FLASHING.store(false, Ordering::SeqCst);
 }
+
+/// 更新托盘提示文字
+pub fn set_tray_tooltip(app: &AppHandle, tooltip: &str) {
+    let tray_id = TrayIconId::new(crate::TRAY_ID);
+    if let Some(tray) = app.tray_by_id(&tray_id) {
+        let _ = tray.set_tooltip(Some(tooltip));
+    }
+}
+
+/// 同步托盘菜单中勿扰模式的勾选状态
+pub fn set_dnd_checked(app: &AppHandle, enabled: bool) {
+    if let Some(item) = app.try_state::<DndMenuItem>() {
+        let _ = item.0.set_checked(enabled);
+    }
+}
@@ -0,0 +1,318 @@
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::{Method, Url};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::error::{AppError, AppResult};
+use crate::notification::channel::{
+    http_client, parse_url, send_with_retry, NotificationChannel, NotificationEvent, NotificationMessage, RetryPolicy,
+};
+
+/// 模板可用的变量
+const TEMPLATE_FIELDS: [&str; 8] = [
+    "action", "title", "content", "links", "triggered_at", "occurred_at", "reminder_uuid", "reminder_title",
+];
+
+/// Webhook 请求方法
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookMethod {
+    #[default]
+    Post,
+    Put,
+    Patch,
+}
+
+impl WebhookMethod {
+    fn as_method(&self) -> Method {
+        match self {
+            Self::Post => Method::POST,
+            Self::Put => Method::PUT,
+            Self::Patch => Method::PATCH,
+        }
+    }
+}
+
+/// Webhook 渠道配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub method: WebhookMethod,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// JSON 请求体模板，字符串中的 `{{变量}}` 会被替换；为空时发送全部变量组成的 JSON 对象
+    #[serde(default)]
+    pub body_template: Option<String>,
+}
+
+/// 自定义 Webhook 通知渠道
+pub struct Webhook {
+    url: Url,
+    method: Method,
+    headers: HeaderMap,
+    template: Option<Value>,
+}
+
+impl Webhook {
+    pub fn new(config: WebhookConfig) -> AppResult<Self> {
+        let url = parse_url(&config.url, "Webhook ")?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            let name = HeaderName::from_bytes(name.trim().as_bytes())
+                .map_err(|_| AppError::Validation(format!("请求头名称无效: {}", name)))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| AppError::Validation(format!("请求头 {} 的值无效", name)))?;
+            headers.insert(name, value);
+        }
+        if !headers.contains_key(CONTENT_TYPE) {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        }
+
+        let template = match config.body_template.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+            Some(template) => {
+                let template: Value = serde_json::from_str(template)
+                    .map_err(|e| AppError::Validation(format!("请求体模板不是有效的 JSON: {}", e)))?;
+                validate_template(&template)?;
+                Some(template)
+            }
+            None => None,
+        };
+
+        Ok(Self { url, method: config.method.as_method(), headers, template })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> Value {
+        let fields = template_fields(message);
+        match self.template {
+            Some(ref template) => render(template, &fields),
+            None => Value::Object(fields),
+        }
+    }
+
+    async fn send_with(&self, message: &NotificationMessage, policy: RetryPolicy) -> AppResult<()> {
+        let client = http_client()?;
+        let body = serde_json::to_vec(&self.body(message))?;
+
+        send_with_retry(policy, || {
+            client
+                .request(self.method.clone(), self.url.clone())
+                .headers(self.headers.clone())
+                .body(body.clone())
+        })
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for Webhook {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        self.send_with(message, RetryPolicy::default()).await
+    }
+}
+
+/// 消息对应的模板变量
+pub fn template_fields(message: &NotificationMessage) -> Map<String, Value> {
+    let reminder = message.reminder.as_ref();
+    // 触发事件的触发时间即事件发生时间，其他事件取提醒最近一次触发时间
+    let triggered_at = match message.event {
+        NotificationEvent::Trigger => Some(message.occurred_at),
+        _ => reminder.and_then(|r| r.last_triggered_at),
+    };
+
+    let mut fields = Map::new();
+    fields.insert("action".into(), message.event.as_str().into());
+    fields.insert("title".into(), message.title.clone().into());
+    fields.insert("content".into(), message.content.clone().into());
+    fields.insert(
+        "links".into(),
+        reminder.and_then(|r| r.links.as_ref())
+            .and_then(|links| serde_json::to_value(links).ok())
+            .unwrap_or_else(|| Value::Array(vec![])),
+    );
+    fields.insert("triggered_at".into(), triggered_at.map(|t| t.to_rfc3339()).into());
+    fields.insert("occurred_at".into(), message.occurred_at.to_rfc3339().into());
+    fields.insert("reminder_uuid".into(), reminder.map(|r| r.uuid.clone()).into());
+    fields.insert("reminder_title".into(), reminder.map(|r| r.title.clone()).into());
+    fields
+}
+
+/// 取出字符串中的 `{{变量}}`
+fn placeholders(text: &str) -> impl Iterator<Item = &str> {
+    text.split("{{").skip(1).filter_map(|part| part.split_once("}}")).map(|(name, _)| name.trim())
+}
+
+/// 检查模板中的变量是否都存在
+fn validate_template(template: &Value) -> AppResult<()> {
+    match template {
+        Value::String(text) => {
+            if let Some(name) = placeholders(text).find(|name| !TEMPLATE_FIELDS.contains(name)) {
+                return Err(AppError::Validation(format!(
+                    "未知的模板变量 {{{{{}}}}}，可用变量: {}",
+                    name,
+                    TEMPLATE_FIELDS.join(", ")
+                )));
+            }
+            Ok(())
+        }
+        Value::Array(items) => items.iter().try_for_each(validate_template),
+        Value::Object(map) => map.values().try_for_each(validate_template),
+        _ => Ok(()),
+    }
+}
+
+/// 变量在字符串中的文本形式
+fn field_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// 替换字符串中的 `{{变量}}`（未知变量保持原样）
+fn interpolate(text: &str, fields: &Map<String, Value>) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        match fields.get(after[..end].trim()) {
+            Some(value) => rendered.push_str(&field_text(value)),
+            None => rendered.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// 渲染模板：字符串恰好是单个 `{{变量}}` 时替换为变量的 JSON 值（如 links 数组），
+/// 否则在字符串中替换为文本，因此渲染结果始终是有效的 JSON
+pub fn render(template: &Value, fields: &Map<String, Value>) -> Value {
+    match template {
+        Value::String(text) => {
+            let trimmed = text.trim();
+            if let Some(name) = trimmed.strip_prefix("{{").and_then(|t| t.strip_suffix("}}")) {
+                if !name.contains("{{") {
+                    if let Some(value) = fields.get(name.trim()) {
+                        return value.clone();
+                    }
+                }
+            }
+
+            Value::String(interpolate(text, fields))
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|item| render(item, fields)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter().map(|(key, value)| (key.clone(), render(value, fields))).collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::test_server::TestServer;
+    use std::time::Duration;
+
+    /// 测试用的重试策略（不等待）
+    const FAST_RETRY: RetryPolicy = RetryPolicy { max_attempts: 3, base_delay: Duration::from_millis(1) };
+
+    fn webhook(url: &str, template: Option<&str>) -> Webhook {
+        Webhook::new(WebhookConfig {
+            url: format!("{}/hook", url),
+            method: WebhookMethod::Put,
+            headers: BTreeMap::from([("X-Token".to_string(), "secret".to_string())]),
+            body_template: template.map(str::to_string),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_rendered_body_and_headers() {
+        let server = TestServer::start(vec![(200, "")]).await;
+        let hook = webhook(&server.url, Some(r#"{"text": "{{title}}: {{ content }}", "kind": "{{action}}", "at": "{{triggered_at}}"}"#));
+        hook.send_with(&NotificationMessage::test("Webhook"), FAST_RETRY).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.path, "/hook");
+        assert_eq!(request.header("X-Token"), Some("secret"));
+        assert_eq!(request.header("Content-Type"), Some("application/json"));
+        assert_eq!(
+            request.json(),
+            serde_json::json!({
+                "text": "叮咚 测试: 这是一条测试消息，说明 Webhook 配置成功！",
+                "kind": "test",
+                "at": null,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_all_fields_without_template() {
+        let server = TestServer::start(vec![(200, "")]).await;
+        webhook(&server.url, None).send_with(&NotificationMessage::test("Webhook"), FAST_RETRY).await.unwrap();
+
+        let body = server.requests()[0].json();
+        let keys: Vec<&str> = body.as_object().unwrap().keys().map(String::as_str).collect();
+        let mut expected = TEMPLATE_FIELDS.to_vec();
+        expected.sort();
+        assert_eq!(keys, expected);
+        assert_eq!(body["links"], serde_json::json!([]));
+        assert_eq!(body["reminder_uuid"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_and_rate_limits() {
+        let server = TestServer::start(vec![(500, ""), (429, ""), (200, "")]).await;
+        webhook(&server.url, None).send_with(&NotificationMessage::test("Webhook"), FAST_RETRY).await.unwrap();
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let server = TestServer::start(vec![(503, ""), (503, ""), (503, ""), (200, "")]).await;
+        let result = webhook(&server.url, None).send_with(&NotificationMessage::test("Webhook"), FAST_RETRY).await;
+        assert!(matches!(result, Err(AppError::Notification(_))));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let server = TestServer::start(vec![(400, "{\"error\":\"bad\"}"), (200, "")]).await;
+        let result = webhook(&server.url, None).send_with(&NotificationMessage::test("Webhook"), FAST_RETRY).await;
+        match result {
+            Err(AppError::Notification(message)) => assert!(message.contains("400"), "{}", message),
+            other => panic!("应返回通知错误: {:?}", other.map(|_| ())),
+        }
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let config = |url: &str, template: Option<&str>| WebhookConfig {
+            url: url.to_string(),
+            method: WebhookMethod::Post,
+            headers: BTreeMap::new(),
+            body_template: template.map(str::to_string),
+        };
+        assert!(Webhook::new(config("ftp://example.com", None)).is_err());
+        assert!(Webhook::new(config("http://example.com", Some("{bad"))).is_err());
+        assert!(Webhook::new(config("http://example.com", Some(r#"{"a": "{{unknown}}"}"#))).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::AppResult;
+use crate::notification::channel::{parse_url, NotificationChannel, NotificationMessage};
+use crate::notification::robot::{self, RobotFormat};
+
+/// 模板卡片最多展示的跳转链接数
+const MAX_JUMP_LINKS: usize = 3;
+
+/// 企业微信群机器人配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeComConfig {
+    /// 机器人 Webhook 地址（含 key）
+    pub webhook: String,
+    #[serde(default)]
+    pub format: RobotFormat,
+}
+
+/// 企业微信群机器人通知渠道
+pub struct WeCom {
+    url: Url,
+    format: RobotFormat,
+}
+
+impl WeCom {
+    pub fn new(config: WeComConfig) -> AppResult<Self> {
+        Ok(Self {
+            url: parse_url(&config.webhook, "企业微信 Webhook ")?,
+            format: config.format,
+        })
+    }
+
+    fn body(&self, message: &NotificationMessage) -> serde_json::Value {
+        let links = robot::links(message);
+
+        // 模板卡片必须有点击跳转地址，没有链接时退回 Markdown
+        if self.format == RobotFormat::Card && !links.is_empty() {
+            let jump_list: Vec<_> = links.iter()
+                .take(MAX_JUMP_LINKS)
+                .map(|link| json!({ "type": 1, "title": link.name, "url": link.url }))
+                .collect();
+            return json!({
+                "msgtype": "template_card",
+                "template_card": {
+                    "card_type": "text_notice",
+                    "main_title": { "title": message.title },
+                    "sub_title_text": message.content,
+                    "jump_list": jump_list,
+                    "card_action": { "type": 1, "url": links[0].url },
+                },
+            });
+        }
+
+        let mut content = format!("### {}", message.title);
+        let text = robot::markdown_text(message, true);
+        if !text.is_empty() {
+            content.push('\n');
+            content.push_str(&text);
+        }
+        json!({
+            "msgtype": "markdown",
+            "markdown": { "content": content },
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WeCom {
+    async fn send(&self, message: &NotificationMessage) -> AppResult<()> {
+        robot::post(self.url.clone(), &self.body(message), "企业微信").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use crate::notification::robot::tests::{message_with_links, FAST_RETRY};
+    use crate::notification::test_server::TestServer;
+
+    fn wecom(url: &str, format: RobotFormat) -> WeCom {
+        WeCom::new(WeComConfig { webhook: format!("{}/cgi-bin/webhook/send?key=abc", url), format }).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_markdown_with_links() {
+        let server = TestServer::start(vec![(200, r#"{"errcode":0,"errmsg":"ok"}"#)]).await;
+        let robot = wecom(&server.url, RobotFormat::Markdown);
+        let message = message_with_links("10 点开会", &[("会议室", "https://example.com/room")]);
+        robot::post_with(robot.url.clone(), &robot.body(&message), "企业微信", FAST_RETRY).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/cgi-bin/webhook/send?key=abc");
+        assert_eq!(
+            request.json(),
+            json!({
+                "msgtype": "markdown",
+                "markdown": { "content": "### 提醒触发: 站会\n10 点开会\n\n[会议室](https://example.com/room)" },
+            })
+        );
+    }
+
+    #[test]
+    fn card_format_uses_template_card() {
+        let robot = wecom("http://127.0.0.1:1", RobotFormat::Card);
+        let links = [
+            ("一", "https://example.com/1"),
+            ("二", "https://example.com/2"),
+            ("三", "https://example.com/3"),
+            ("四", "https://example.com/4"),
+        ];
+        let body = robot.body(&message_with_links("10 点开会", &links));
+        assert_eq!(
+            body,
+            json!({
+                "msgtype": "template_card",
+                "template_card": {
+                    "card_type": "text_notice",
+                    "main_title": { "title": "提醒触发: 站会" },
+                    "sub_title_text": "10 点开会",
+                    "jump_list": [
+                        { "type": 1, "title": "一", "url": "https://example.com/1" },
+                        { "type": 1, "title": "二", "url": "https://example.com/2" },
+                        { "type": 1, "title": "三", "url": "https://example.com/3" },
+                    ],
+                    "card_action": { "type": 1, "url": "https://example.com/1" },
+                },
+            })
+        );
+
+        // 没有链接时退回 Markdown
+        assert_eq!(robot.body(&NotificationMessage::test("企业微信"))["msgtype"], "markdown");
+    }
+
+    #[tokio::test]
+    async fn reports_invalid_key() {
+        let server = TestServer::start(vec![(200, r#"{"errcode":93000,"errmsg":"invalid webhook url"}"#)]).await;
+        let robot = wecom(&server.url, RobotFormat::Markdown);
+        let result = robot::post_with(robot.url.clone(), &robot.body(&NotificationMessage::test("企业微信")), "企业微信", FAST_RETRY).await;
+        match result {
+            Err(AppError::Notification(message)) => assert!(message.contains("企业微信返回错误 93000"), "{}", message),
+            other => panic!("应返回通知错误: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Datelike, Local};
+
+use crate::models::settings::{AppSettings, QuietHours};
+use crate::storage::settings_repo;
+use crate::utils::time::parse_time;
+
+/// 是否处于勿扰状态（手动开启或处于任一勿扰时段）
+pub fn is_active(settings: &AppSettings, now: DateTime<Local>) -> bool {
+    settings.dnd_enabled || settings.quiet_hours.iter().any(|quiet| in_quiet_hours(quiet, now))
+}
+
+/// 当前是否处于勿扰状态（读取设置失败时视为未开启）
+pub fn is_active_now() -> bool {
+    settings_repo::get_all()
+        .map(|settings| is_active(&settings, Local::now()))
+        .unwrap_or(false)
+}
+
+/// 是否处于勿扰时段：星期按时段开始的那天判断，跨午夜的时段延续到次日
+fn in_quiet_hours(quiet: &QuietHours, now: DateTime<Local>) -> bool {
+    if !quiet.enabled {
+        return false;
+    }
+    let (Some(start), Some(end)) = (parse_time(&quiet.start), parse_time(&quiet.end)) else {
+        return false;
+    };
+
+    let time = now.time();
+    let today = now.date_naive();
+    let start_date = if start <= end {
+        if time < start || time >= end {
+            return false;
+        }
+        today
+    } else if time >= start {
+        today
+    } else if time < end {
+        match today.pred_opt() {
+            Some(date) => date,
+            None => return false,
+        }
+    } else {
+        return false;
+    };
+
+    let weekday = start_date.weekday().num_days_from_monday() as u8 + 1;
+    quiet.weekdays.is_empty() || quiet.weekdays.contains(&weekday)
+}
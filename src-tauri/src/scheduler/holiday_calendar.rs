@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::RwLock;
+use chrono::{Datelike, NaiveDate, Weekday};
+use once_cell::sync::Lazy;
+
+use crate::error::AppResult;
+use crate::models::holiday::{HolidayCalendar, HolidayKind, HolidaySource};
+use crate::storage::holiday_repo;
+
+/// 内置的中国法定节假日与调休日历
+///
+/// 国务院办公厅通常在每年年底公布下一年的放假安排，届时在 `resources/holidays/cn.json`
+/// 中追加新一年的日期并更新 `version`；用户也可以通过导入 JSON 日历自行补充。
+const BUNDLED_CALENDAR: &str = include_str!("../../resources/holidays/cn.json");
+
+/// 内存中的节假日日历（日期 -> 类型），供调度计算时快速查询
+static CALENDAR: Lazy<RwLock<HashMap<NaiveDate, HolidayKind>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 日历覆盖的最后一年，之后的日期只能按普通周末判断
+static LAST_COVERED_YEAR: AtomicI32 = AtomicI32::new(i32::MAX);
+
+/// 已提示过超出日历范围的最大年份（逐日计算时避免重复输出）
+static WARNED_YEAR: AtomicI32 = AtomicI32::new(i32::MIN);
+
+/// 解析内置日历
+pub fn bundled_calendar() -> Result<HolidayCalendar, serde_json::Error> {
+    serde_json::from_str(BUNDLED_CALENDAR)
+}
+
+/// 启动时写入内置日历并加载到内存（用户导入的数据保留且优先）
+pub fn init() -> AppResult<()> {
+    let calendar = bundled_calendar()?;
+    holiday_repo::replace_source(HolidaySource::Bundled, &calendar.days)?;
+    reload()?;
+    match last_covered_year() {
+        Some(year) => println!("[节假日] 已加载内置日历 {}（覆盖至 {} 年）", calendar.version, year),
+        None => println!("[节假日] 已加载内置日历 {}", calendar.version),
+    }
+    Ok(())
+}
+
+/// 从数据库重新加载日历到内存
+pub fn reload() -> AppResult<()> {
+    let days = holiday_repo::get_effective(None)?;
+    let last_year = days.iter().map(|day| day.date.year()).max().unwrap_or(i32::MAX);
+    let mut calendar = CALENDAR.write().unwrap();
+    *calendar = days.into_iter().map(|day| (day.date, day.kind)).collect();
+    LAST_COVERED_YEAR.store(last_year, Ordering::Relaxed);
+    WARNED_YEAR.store(i32::MIN, Ordering::Relaxed);
+    Ok(())
+}
+
+/// 日历覆盖的最后一年（日历为空时为 None）
+pub fn last_covered_year() -> Option<i32> {
+    Some(LAST_COVERED_YEAR.load(Ordering::Relaxed)).filter(|year| *year != i32::MAX)
+}
+
+/// 查询日期超出日历范围时提示（每个年份只提示一次）
+fn warn_if_uncovered(date: NaiveDate) {
+    let Some(last_year) = last_covered_year() else {
+        return;
+    };
+    let year = date.year();
+    if year > last_year && WARNED_YEAR.fetch_max(year, Ordering::Relaxed) < year {
+        eprintln!(
+            "[节假日] {} 年不在节假日日历范围内（最后覆盖 {} 年），节假日和调休按普通周末判断，请更新或导入日历",
+            year, last_year
+        );
+    }
+}
+
+/// 是否为法定节假日
+pub fn is_holiday(date: NaiveDate) -> bool {
+    warn_if_uncovered(date);
+    CALENDAR.read().unwrap().get(&date) == Some(&HolidayKind::Holiday)
+}
+
+/// 是否为中国工作日：调休上班日一定是工作日，否则为非节假日的周一至周五
+pub fn is_china_workday(date: NaiveDate) -> bool {
+    warn_if_uncovered(date);
+    match CALENDAR.read().unwrap().get(&date) {
+        Some(HolidayKind::Workday) => true,
+        Some(HolidayKind::Holiday) => false,
+        None => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+    }
+}
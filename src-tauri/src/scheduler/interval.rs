@@ -0,0 +1,162 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime};
+
+use crate::models::reminder::{IntervalUnit, Reminder};
+use crate::scheduler::zone::ReminderZone;
+use crate::utils::time::parse_time;
+
+/// 间隔重复的计算参数
+struct IntervalSpec {
+    zone: ReminderZone,
+    /// 锚点时间，第一次触发即为锚点
+    anchor: DateTime<Local>,
+    value: i64,
+    unit: IntervalUnit,
+    /// 每天的生效时段（仅对分钟/小时间隔有效）
+    window: Option<(NaiveTime, NaiveTime)>,
+}
+
+impl IntervalSpec {
+    fn from_reminder(reminder: &Reminder) -> Option<Self> {
+        let value = reminder.interval_value.filter(|v| *v > 0)? as i64;
+        let unit = reminder.interval_unit.clone().unwrap_or_default();
+        let zone = ReminderZone::of(reminder);
+        let anchor = reminder.anchor_at.unwrap_or(reminder.created_at).with_timezone(&Local);
+
+        let window = match (&reminder.window_start, &reminder.window_end) {
+            (Some(start), Some(end)) if unit != IntervalUnit::Days => {
+                Some((parse_time(start)?, parse_time(end)?))
+            }
+            _ => None,
+        };
+
+        Some(Self { zone, anchor, value, unit, window })
+    }
+
+    fn step(&self) -> Duration {
+        match self.unit {
+            IntervalUnit::Minutes => Duration::minutes(self.value),
+            IntervalUnit::Hours => Duration::hours(self.value),
+            IntervalUnit::Days => Duration::days(self.value),
+        }
+    }
+
+    /// 指定日期的生效时段（结束时间早于开始时间时视为跨午夜）
+    fn window_on(&self, date: NaiveDate) -> Option<(DateTime<Local>, DateTime<Local>)> {
+        let (start, end) = self.window?;
+        let start_at = self.zone.at(date, start)?;
+        let end_date = if end < start { date.succ_opt()? } else { date };
+        let end_at = self.zone.at(end_date, end)?;
+        Some((start_at, end_at))
+    }
+
+    /// 锚点在提醒时区的日期
+    fn anchor_date(&self) -> NaiveDate {
+        self.zone.date(self.anchor)
+    }
+
+    /// 第 k 个按天间隔的触发时间（保持锚点在提醒时区的时间）
+    fn nth_day(&self, k: i64) -> Option<DateTime<Local>> {
+        let date = self.anchor_date() + Duration::days(k * self.value);
+        self.zone.at(date, self.zone.naive(self.anchor).time())
+    }
+}
+
+/// 向上取整的整数除法（除数为正）
+fn div_ceil(a: i64, b: i64) -> i64 {
+    a.div_euclid(b) + if a.rem_euclid(b) > 0 { 1 } else { 0 }
+}
+
+/// 计算间隔提醒在 `after` 之后（不含）的下一次触发时间，最多向后查找 `max_days` 天
+pub fn next_after(reminder: &Reminder, after: DateTime<Local>, max_days: i64) -> Option<DateTime<Local>> {
+    let spec = IntervalSpec::from_reminder(reminder)?;
+
+    if after < spec.anchor && spec.window.is_none() {
+        return Some(spec.anchor);
+    }
+
+    if spec.unit == IntervalUnit::Days {
+        let days = (spec.zone.date(after) - spec.anchor_date()).num_days().max(0);
+        let first = days / spec.value;
+        return (first..first + 3)
+            .filter_map(|k| spec.nth_day(k))
+            .find(|occurrence| *occurrence > after);
+    }
+
+    let step = spec.step().num_seconds();
+
+    if spec.window.is_none() {
+        let elapsed = (after - spec.anchor).num_seconds();
+        let k = elapsed.div_euclid(step) + 1;
+        return Some(spec.anchor + Duration::seconds(k * step));
+    }
+
+    // 有生效时段：每天从时段开始时间重新计算，前一天的跨午夜时段也要检查
+    let mut date = spec.zone.date(after.max(spec.anchor)).pred_opt()?;
+    for _ in 0..=max_days {
+        if let Some((start, end)) = spec.window_on(date) {
+            let mut k = 0;
+            if after >= start {
+                k = (after - start).num_seconds().div_euclid(step) + 1;
+            }
+            if spec.anchor > start {
+                k = k.max(div_ceil((spec.anchor - start).num_seconds(), step));
+            }
+            let occurrence = start + Duration::seconds(k * step);
+            if occurrence <= end {
+                return Some(occurrence);
+            }
+        }
+        date = date.succ_opt()?;
+    }
+
+    None
+}
+
+/// 查找间隔提醒在 (since, until] 区间内最近一次应触发的时间，最多回溯 `max_days` 天
+pub fn last_between(
+    reminder: &Reminder,
+    since: DateTime<Local>,
+    until: DateTime<Local>,
+    max_days: i64,
+) -> Option<DateTime<Local>> {
+    let spec = IntervalSpec::from_reminder(reminder)?;
+    let since = since.max(until - Duration::days(max_days));
+
+    if until < spec.anchor {
+        return None;
+    }
+
+    let occurrence = if spec.unit == IntervalUnit::Days {
+        let days = (spec.zone.date(until) - spec.anchor_date()).num_days();
+        let last = days / spec.value;
+        (last - 1..=last)
+            .rev()
+            .filter_map(|k| spec.nth_day(k))
+            .find(|occurrence| *occurrence <= until)?
+    } else if spec.window.is_none() {
+        let step = spec.step().num_seconds();
+        let k = (until - spec.anchor).num_seconds().div_euclid(step);
+        spec.anchor + Duration::seconds(k * step)
+    } else {
+        let step = spec.step().num_seconds();
+        let mut date = spec.zone.date(until);
+        let mut found = None;
+        while date >= spec.zone.date(since).pred_opt()? {
+            if let Some((start, end)) = spec.window_on(date) {
+                let limit = end.min(until);
+                if limit >= start {
+                    let k = (limit - start).num_seconds().div_euclid(step);
+                    let occurrence = start + Duration::seconds(k * step);
+                    if occurrence >= spec.anchor {
+                        found = Some(occurrence);
+                        break;
+                    }
+                }
+            }
+            date = date.pred_opt()?;
+        }
+        found?
+    };
+
+    (occurrence > since && occurrence >= spec.anchor).then_some(occurrence)
+}
@@ -1,15 +1,58 @@
 use tauri::{AppHandle, Emitter, Manager};
-use tokio::time::{interval, Duration};
-use tokio::sync::Mutex;
-use chrono::{Local, Timelike, Datelike, DateTime, Utc};
-use std::collections::HashMap;
+use tokio::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use chrono::{Local, DateTime, Utc};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::sync::Arc;
 use once_cell::sync::Lazy;
 
-use crate::storage::{reminder_repo, reminder_log_repo, settings_repo};
-use crate::models::reminder::{Reminder, RemindType};
+use crate::error::{AppError, AppResult};
+use crate::storage::{reminder_repo, reminder_log_repo, settings_repo, scheduler_queue_repo};
+use crate::models::reminder::{Reminder, ReminderOccurrence, RemindType, UpcomingReminder, UpcomingSource};
 use crate::models::reminder_log::LogAction;
-use crate::notification::{serverchan, popup, tray};
+use crate::models::queue_item::{QueueItem, QueueType};
+use crate::models::settings::MissedReminderMode;
+use crate::notification::{popup, tray};
+use crate::notification::channel::{self, NotificationMessage};
+use crate::scheduler::{dnd, occurrence};
+use crate::scheduler::zone::ReminderZone;
+use crate::utils::time::format_remaining;
+
+/// 晚于原定时间超过该秒数才视为错过，否则按正常提醒触发
+const MISSED_GRACE_SECS: i64 = 60;
+
+/// 补发错过提醒时最多回溯的天数
+const MAX_CATCH_UP_DAYS: i64 = 7;
+
+/// 实际唤醒时间晚于预期超过该秒数视为时钟跳变（休眠唤醒、系统时间调整等）
+const CLOCK_JUMP_THRESHOLD_SECS: i64 = 60;
+
+/// 单次休眠的最长时间，保证系统休眠或调整时间后能及时发现时钟跳变
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// 暂停期间最多记录的跳过次数
+const MAX_PAUSE_SKIP_LOGS: usize = 500;
+
+/// 调度器事件
+#[derive(Debug)]
+pub enum SchedulerEvent {
+    /// 提醒数据已变更，需要重新计算触发时间
+    RemindersChanged,
+    /// 延迟/循环/超时队列已变更，需要重新计算唤醒时间
+    QueueChanged,
+    /// 勿扰状态已变更，需要检查是否投递勿扰期间暂缓的提醒
+    DndChanged,
+    /// 停止调度器（恢复备份前调用）
+    Shutdown,
+}
+
+/// 计划触发项（按触发时间排序）
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ScheduledFire {
+    pub fire_at: DateTime<Utc>,
+    pub reminder_uuid: String,
+}
 
 /// 延迟提醒项
 #[derive(Debug, Clone)]
@@ -27,6 +70,18 @@ pub struct LoopItem {
     pub original_triggered_at: DateTime<Utc>,
 }
 
+/// 调度器事件发送端（调度器启动后设置）
+static SCHEDULER_TX: std::sync::Mutex<Option<mpsc::UnboundedSender<SchedulerEvent>>> =
+    std::sync::Mutex::new(None);
+
+/// 调度器任务（用于停止后等待其退出）
+static SCHEDULER_TASK: std::sync::Mutex<Option<tauri::async_runtime::JoinHandle<()>>> =
+    std::sync::Mutex::new(None);
+
+/// 全局计划触发队列（小顶堆，堆顶为最早触发的提醒）
+static SCHEDULE: Lazy<Arc<Mutex<BinaryHeap<Reverse<ScheduledFire>>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(BinaryHeap::new())));
+
 /// 全局延迟队列
 static SNOOZE_QUEUE: Lazy<Arc<Mutex<HashMap<String, SnoozeItem>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
@@ -39,6 +94,90 @@ static LOOP_QUEUE: Lazy<Arc<Mutex<HashMap<String, LoopItem>>>> =
 static TIMEOUT_QUEUE: Lazy<Arc<Mutex<HashMap<String, (DateTime<Utc>, i32)>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+/// 勿扰期间暂缓弹窗的提醒（uuid -> 触发时间），勿扰结束后逐个弹窗投递
+static DEFERRED_QUEUE: Lazy<Arc<Mutex<HashMap<String, DateTime<Utc>>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+/// 发送调度器事件（调度器未启动时忽略）
+fn send_event(event: SchedulerEvent) {
+    if let Some(tx) = SCHEDULER_TX.lock().unwrap().as_ref() {
+        let _ = tx.send(event);
+    }
+}
+
+/// 通知调度器提醒数据已变更（创建、修改、启用/禁用、删除后调用）
+pub fn notify_reminders_changed() {
+    send_event(SchedulerEvent::RemindersChanged);
+}
+
+/// 通知调度器勿扰状态已变更（开关勿扰模式、修改勿扰时段后调用）
+pub fn notify_dnd_changed() {
+    send_event(SchedulerEvent::DndChanged);
+}
+
+/// 持久化队列项（失败只记录日志，不影响内存队列）
+fn persist_queue_item(item: QueueItem) {
+    if let Err(e) = scheduler_queue_repo::upsert(&item) {
+        eprintln!("[调度器] 保存队列项失败: {} ({})", item.reminder_uuid, e);
+    }
+}
+
+/// 删除持久化的队列项
+fn unpersist_queue_item(queue_type: QueueType, reminder_uuid: &str) {
+    if let Err(e) = scheduler_queue_repo::remove(queue_type, reminder_uuid) {
+        eprintln!("[调度器] 删除队列项失败: {} ({})", reminder_uuid, e);
+    }
+}
+
+/// 从数据库恢复延迟、循环、超时队列（启动时调用）
+async fn restore_queues() {
+    let items = match scheduler_queue_repo::get_all() {
+        Ok(items) => items,
+        Err(e) => {
+            eprintln!("[调度器] 恢复队列失败: {}", e);
+            return;
+        }
+    };
+
+    let mut snooze_queue = SNOOZE_QUEUE.lock().await;
+    let mut loop_queue = LOOP_QUEUE.lock().await;
+    let mut timeout_queue = TIMEOUT_QUEUE.lock().await;
+    let mut deferred_queue = DEFERRED_QUEUE.lock().await;
+
+    for item in items {
+        match item.queue_type {
+            QueueType::Snooze => {
+                snooze_queue.insert(item.reminder_uuid.clone(), SnoozeItem {
+                    reminder_uuid: item.reminder_uuid,
+                    snooze_until: item.due_at,
+                    original_triggered_at: item.original_triggered_at,
+                });
+            }
+            QueueType::Loop => {
+                loop_queue.insert(item.reminder_uuid.clone(), LoopItem {
+                    reminder_uuid: item.reminder_uuid,
+                    next_loop_at: item.due_at,
+                    original_triggered_at: item.original_triggered_at,
+                });
+            }
+            QueueType::Timeout => {
+                let timeout_minutes = item.timeout_minutes.unwrap_or_else(|| {
+                    item.due_at.signed_duration_since(item.original_triggered_at).num_minutes() as i32
+                });
+                timeout_queue.insert(item.reminder_uuid, (item.original_triggered_at, timeout_minutes));
+            }
+            QueueType::Deferred => {
+                deferred_queue.insert(item.reminder_uuid, item.original_triggered_at);
+            }
+        }
+    }
+
+    println!(
+        "[调度器] 已恢复队列: 延迟 {} 个, 循环 {} 个, 超时检测 {} 个, 勿扰暂缓 {} 个",
+        snooze_queue.len(), loop_queue.len(), timeout_queue.len(), deferred_queue.len()
+    );
+}
+
 /// 添加延迟提醒到队列
 pub async fn add_snooze(reminder_uuid: &str, minutes: i32, triggered_at: DateTime<Utc>) {
     let snooze_until = Utc::now() + chrono::Duration::minutes(minutes as i64);
@@ -49,15 +188,24 @@ pub async fn add_snooze(reminder_uuid: &str, minutes: i32, triggered_at: DateTim
         snooze_until,
         original_triggered_at: triggered_at,
     };
+    persist_queue_item(QueueItem {
+        queue_type: QueueType::Snooze,
+        reminder_uuid: reminder_uuid.to_string(),
+        due_at: snooze_until,
+        original_triggered_at: triggered_at,
+        timeout_minutes: None,
+    });
     let mut queue = SNOOZE_QUEUE.lock().await;
     queue.insert(reminder_uuid.to_string(), item);
     println!("[调度器] 延迟队列当前大小: {}", queue.len());
+    send_event(SchedulerEvent::QueueChanged);
 }
 
 /// 从延迟队列移除
 pub async fn remove_snooze(reminder_uuid: &str) {
     let mut queue = SNOOZE_QUEUE.lock().await;
     queue.remove(reminder_uuid);
+    unpersist_queue_item(QueueType::Snooze, reminder_uuid);
 }
 
 /// 添加循环提醒到队列
@@ -68,54 +216,144 @@ pub async fn add_loop(reminder_uuid: &str, interval_minutes: i32, triggered_at:
         next_loop_at,
         original_triggered_at: triggered_at,
     };
+    persist_queue_item(QueueItem {
+        queue_type: QueueType::Loop,
+        reminder_uuid: reminder_uuid.to_string(),
+        due_at: next_loop_at,
+        original_triggered_at: triggered_at,
+        timeout_minutes: None,
+    });
     let mut queue = LOOP_QUEUE.lock().await;
     queue.insert(reminder_uuid.to_string(), item);
+    send_event(SchedulerEvent::QueueChanged);
 }
 
 /// 从循环队列移除（完成时调用）
 pub async fn remove_loop(reminder_uuid: &str) {
     let mut queue = LOOP_QUEUE.lock().await;
     queue.remove(reminder_uuid);
+    unpersist_queue_item(QueueType::Loop, reminder_uuid);
 }
 
 /// 添加超时检测
 pub async fn add_timeout_check(reminder_uuid: &str, timeout_minutes: i32) {
+    let now = Utc::now();
+    persist_queue_item(QueueItem {
+        queue_type: QueueType::Timeout,
+        reminder_uuid: reminder_uuid.to_string(),
+        due_at: now + chrono::Duration::minutes(timeout_minutes as i64),
+        original_triggered_at: now,
+        timeout_minutes: Some(timeout_minutes),
+    });
     let mut queue = TIMEOUT_QUEUE.lock().await;
-    queue.insert(reminder_uuid.to_string(), (Utc::now(), timeout_minutes));
+    queue.insert(reminder_uuid.to_string(), (now, timeout_minutes));
+    send_event(SchedulerEvent::QueueChanged);
 }
 
 /// 从超时队列移除
 pub async fn remove_timeout_check(reminder_uuid: &str) {
     let mut queue = TIMEOUT_QUEUE.lock().await;
     queue.remove(reminder_uuid);
+    unpersist_queue_item(QueueType::Timeout, reminder_uuid);
+}
+
+/// 勿扰期间暂缓弹窗
+async fn add_deferred(reminder_uuid: &str, triggered_at: DateTime<Utc>) {
+    persist_queue_item(QueueItem {
+        queue_type: QueueType::Deferred,
+        reminder_uuid: reminder_uuid.to_string(),
+        due_at: triggered_at,
+        original_triggered_at: triggered_at,
+        timeout_minutes: None,
+    });
+    DEFERRED_QUEUE.lock().await.insert(reminder_uuid.to_string(), triggered_at);
+}
+
+/// 从勿扰暂缓队列移除（完成、延迟或关闭时调用）
+pub async fn remove_deferred(reminder_uuid: &str) {
+    let mut queue = DEFERRED_QUEUE.lock().await;
+    if queue.remove(reminder_uuid).is_some() {
+        unpersist_queue_item(QueueType::Deferred, reminder_uuid);
+    }
+}
+
+/// 在后台启动调度器
+pub fn spawn_scheduler(app: AppHandle) {
+    let handle = tauri::async_runtime::spawn(start_scheduler(app));
+    *SCHEDULER_TASK.lock().unwrap() = Some(handle);
+}
+
+/// 停止调度器并等待其退出，清空内存中的计划和队列（持久化的队列项保留在数据库中）
+pub async fn stop_scheduler() {
+    send_event(SchedulerEvent::Shutdown);
+    let handle = SCHEDULER_TASK.lock().unwrap().take();
+    if let Some(handle) = handle {
+        let _ = handle.await;
+    }
+
+    SCHEDULE.lock().await.clear();
+    SNOOZE_QUEUE.lock().await.clear();
+    LOOP_QUEUE.lock().await.clear();
+    TIMEOUT_QUEUE.lock().await.clear();
+    DEFERRED_QUEUE.lock().await.clear();
 }
 
 /// 启动调度器
+///
+/// 为每个启用的提醒计算精确的下次触发时间并放入小顶堆，休眠到最早的触发时间（或队列到期时间），
+/// 提醒数据变更时通过事件通道提前唤醒并重新计算。
 pub async fn start_scheduler(app: AppHandle) {
     println!("[调度器] 启动中...");
 
-    // 等待到下一分钟的整点
-    let now = Local::now();
-    let wait_secs = 60 - now.second() as u64;
-    println!("[调度器] 当前时间: {}, 等待 {} 秒后开始检查", now.format("%H:%M:%S"), wait_secs);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    *SCHEDULER_TX.lock().unwrap() = Some(tx);
 
-    if wait_secs > 0 && wait_secs < 60 {
-        tokio::time::sleep(Duration::from_secs(wait_secs)).await;
+    // 加载节假日日历（工作日提醒和跳过节假日依赖）
+    if let Err(e) = super::holiday_calendar::init() {
+        eprintln!("[调度器] 加载节假日日历失败: {}", e);
     }
 
-    println!("[调度器] 开始每分钟检查提醒");
+    // 恢复上次运行时未处理完的队列
+    restore_queues().await;
+
+    // 补发应用未运行期间错过的提醒
+    catch_up_missed_reminders(&app).await;
 
-    // 每分钟检查一次
-    let mut interval = interval(Duration::from_secs(60));
+    // 计算所有提醒的下次触发时间
+    rebuild_schedule().await;
 
     loop {
-        interval.tick().await;
+        let now = Utc::now();
+        let sleep_duration = next_wake_at()
+            .await
+            .map(|wake_at| wake_at.signed_duration_since(now).to_std().unwrap_or(Duration::ZERO))
+            .unwrap_or(MAX_SLEEP)
+            .min(MAX_SLEEP);
+        let expected_wake = now + chrono::Duration::from_std(sleep_duration).unwrap_or_default();
+
+        let event = tokio::select! {
+            _ = tokio::time::sleep(sleep_duration) => None,
+            event = rx.recv() => match event {
+                Some(SchedulerEvent::Shutdown) | None => break,
+                Some(event) => Some(event),
+            },
+        };
 
-        let check_time = Local::now();
-        println!("[调度器] {} 检查提醒...", check_time.format("%H:%M:%S"));
+        // 检测时钟跳变：系统休眠期间单调时钟暂停，唤醒后实际时间会远超预期
+        if event.is_none() {
+            let drift = Utc::now().signed_duration_since(expected_wake).num_seconds();
+            if drift > CLOCK_JUMP_THRESHOLD_SECS {
+                println!("[调度器] 检测到时钟跳变（晚于预期 {} 秒），补发错过的提醒", drift);
+                catch_up_missed_reminders(&app).await;
+                rebuild_schedule().await;
+            }
+        }
 
-        // 检查常规提醒
-        check_reminders(&app).await;
+        // 在后台线程检测演示模式，弹窗只读取缓存结果
+        let _ = tokio::task::spawn_blocking(popup::refresh_presentation).await;
+
+        // 触发到期的常规提醒
+        fire_due_reminders(&app).await;
 
         // 检查延迟队列
         check_snooze_queue(&app).await;
@@ -125,19 +363,314 @@ pub async fn start_scheduler(app: AppHandle) {
 
         // 检查超时
         check_timeout_queue(&app).await;
+
+        // 勿扰结束后投递暂缓的提醒
+        deliver_deferred_reminders(&app).await;
+
+        // 退出演示模式（或暂缓超时）后显示暂缓的弹窗
+        popup::flush_popup_queue(&app);
+
+        if let Some(SchedulerEvent::RemindersChanged) = event {
+            rebuild_schedule().await;
+        }
+
+        update_tray_tooltip(&app).await;
+    }
+
+    *SCHEDULER_TX.lock().unwrap() = None;
+    println!("[调度器] 已停止");
+}
+
+/// 提醒剩余的触发次数（未设置最大触发次数时为 None）
+fn remaining_occurrences(reminder: &Reminder) -> Option<usize> {
+    let max = reminder.max_occurrences.filter(|n| *n > 0)?;
+    let triggered = reminder_log_repo::count_triggered(&reminder.uuid).unwrap_or_else(|e| {
+        eprintln!("[调度器] 获取触发次数失败: {}", e);
+        0
+    });
+    Some((max - triggered).max(0) as usize)
+}
+
+/// 计算下次触发的起点：不早于上次触发时间（避免同一时间点重复触发），也不早于暂停截止时间
+fn schedule_cursor(reminder: &Reminder, now: DateTime<Local>) -> DateTime<Local> {
+    [reminder.last_triggered_at, reminder.paused_until]
+        .into_iter()
+        .flatten()
+        .map(|t| t.with_timezone(&Local))
+        .fold(now, |cursor, t| cursor.max(t))
+}
+
+/// 计算提醒在 `now` 之后的下次触发时间
+fn compute_next_fire(reminder: &Reminder, now: DateTime<Local>) -> Option<DateTime<Utc>> {
+    if remaining_occurrences(reminder) == Some(0) {
+        return None;
+    }
+
+    let after = schedule_cursor(reminder, now);
+
+    occurrence::next_occurrence(reminder, after).map(|dt| dt.with_timezone(&Utc))
+}
+
+/// 重新计算所有启用提醒的下次触发时间
+async fn rebuild_schedule() {
+    let reminders = match reminder_repo::get_enabled_reminders() {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("[调度器] 获取提醒失败: {}", e);
+            return;
+        }
+    };
+
+    let now = Local::now();
+    let mut heap = BinaryHeap::new();
+
+    for reminder in &reminders {
+        if expire_if_finished(reminder).await {
+            continue;
+        }
+        if let Some(fire_at) = compute_next_fire(reminder, now) {
+            heap.push(Reverse(ScheduledFire {
+                fire_at,
+                reminder_uuid: reminder.uuid.clone(),
+            }));
+        }
+    }
+
+    if let Some(Reverse(next)) = heap.peek() {
+        println!(
+            "[调度器] 已计划 {} 个提醒，最近一次: {} ({})",
+            heap.len(),
+            next.fire_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+            next.reminder_uuid
+        );
+    } else {
+        println!("[调度器] 没有需要计划的提醒");
+    }
+
+    *SCHEDULE.lock().await = heap;
+}
+
+/// 获取最近需要处理的时间点（计划触发、延迟、循环、超时中最早的一个）
+async fn next_wake_at() -> Option<DateTime<Utc>> {
+    let schedule_next = SCHEDULE.lock().await.peek().map(|Reverse(fire)| fire.fire_at);
+    let snooze_next = SNOOZE_QUEUE.lock().await.values().map(|item| item.snooze_until).min();
+    let loop_next = LOOP_QUEUE.lock().await.values().map(|item| item.next_loop_at).min();
+    let timeout_next = TIMEOUT_QUEUE.lock().await.values()
+        .map(|(triggered_at, timeout_minutes)| {
+            *triggered_at + chrono::Duration::minutes(*timeout_minutes as i64)
+        })
+        .min();
+
+    [schedule_next, snooze_next, loop_next, timeout_next]
+        .into_iter()
+        .flatten()
+        .min()
+}
+
+/// 跳过提醒的下一次触发（暂停到下一次触发时间），返回被跳过的触发时间
+pub fn skip_next_occurrence(reminder: &Reminder) -> AppResult<Option<DateTime<Utc>>> {
+    let Some(next) = compute_next_fire(reminder, Local::now()) else {
+        return Ok(None);
+    };
+
+    reminder_repo::set_paused_until(&reminder.uuid, Some(next))?;
+    reminder_log_repo::create(
+        &reminder.uuid,
+        LogAction::Skipped,
+        next,
+        None,
+        Some(format!("跳过 {} 的提醒", next.with_timezone(&Local).format("%Y-%m-%d %H:%M"))),
+    )?;
+    println!("[调度器] 跳过下一次提醒: {} ({})", reminder.title, next.with_timezone(&Local).format("%Y-%m-%d %H:%M"));
+
+    notify_reminders_changed();
+    Ok(Some(next))
+}
+
+/// 暂停提醒到指定时间，期间的触发全部跳过（每一次都记录跳过日志），之后自动恢复
+pub fn pause_reminder(reminder: &Reminder, until: DateTime<Utc>) -> AppResult<()> {
+    let now = Local::now();
+
+    // 重新暂停时撤销之前记录的、尚未到来的跳过
+    reminder_log_repo::delete_skipped_after(&reminder.uuid, now.with_timezone(&Utc))?;
+    reminder_repo::set_paused_until(&reminder.uuid, Some(until))?;
+
+    let note = format!("暂停至 {}", until.with_timezone(&Local).format("%Y-%m-%d %H:%M"));
+    let mut cursor = reminder.last_triggered_at.map_or(now, |t| now.max(t.with_timezone(&Local)));
+    let mut remaining = remaining_occurrences(reminder).unwrap_or(usize::MAX).min(MAX_PAUSE_SKIP_LOGS);
+    if reminder.remind_type == RemindType::Once {
+        remaining = remaining.min(1);
+    }
+    while remaining > 0 {
+        let Some(next) = occurrence::next_occurrence(reminder, cursor) else {
+            break;
+        };
+        if next.with_timezone(&Utc) > until {
+            break;
+        }
+        reminder_log_repo::create(&reminder.uuid, LogAction::Skipped, next.with_timezone(&Utc), None, Some(note.clone()))?;
+        cursor = next;
+        remaining -= 1;
     }
+    println!("[调度器] 暂停提醒: {} 至 {}", reminder.title, until.with_timezone(&Local).format("%Y-%m-%d %H:%M"));
+
+    notify_reminders_changed();
+    Ok(())
+}
+
+/// 取消暂停（包括取消跳过下一次），尚未到来的跳过记录一并撤销
+pub fn resume_reminder(reminder: &Reminder) -> AppResult<()> {
+    reminder_repo::set_paused_until(&reminder.uuid, None)?;
+    reminder_log_repo::delete_skipped_after(&reminder.uuid, Utc::now())?;
+    notify_reminders_changed();
+    Ok(())
+}
+
+/// 获取单个提醒接下来的 `count` 次计划触发（同时给出提醒时区和本机时区的时间）
+pub fn get_reminder_occurrences(reminder: &Reminder, count: usize) -> Vec<ReminderOccurrence> {
+    let zone = ReminderZone::of(reminder);
+    let now = Local::now();
+    let after = schedule_cursor(reminder, now);
+
+    let count = remaining_occurrences(reminder).map_or(count, |remaining| count.min(remaining));
+    occurrence::upcoming_occurrences(reminder, after, count)
+        .into_iter()
+        .map(|fire_at| ReminderOccurrence {
+            fire_at: fire_at.with_timezone(&Utc),
+            time_zone: zone.name(),
+            zone_time: zone.fixed(fire_at),
+            local_time: fire_at.fixed_offset(),
+        })
+        .collect()
 }
 
-/// 检查常规提醒
-async fn check_reminders(app: &AppHandle) {
+/// 获取接下来的 `limit` 次触发（包含所有提醒的计划触发以及延迟、循环队列），按时间排序
+pub async fn get_upcoming_reminders(limit: usize) -> AppResult<Vec<UpcomingReminder>> {
+    let reminders = reminder_repo::get_enabled_reminders()?;
     let now = Local::now();
-    let current_time = now.format("%H:%M").to_string();
-    let current_weekday = now.weekday().num_days_from_monday() as u8 + 1;
-    let current_day = now.day() as u8;
-    let today = now.format("%Y-%m-%d").to_string();
+    let mut upcoming = vec![];
+
+    for reminder in &reminders {
+        let after = schedule_cursor(reminder, now);
+
+        let count = remaining_occurrences(reminder).map_or(limit, |remaining| limit.min(remaining));
+        for fire_at in occurrence::upcoming_occurrences(reminder, after, count) {
+            upcoming.push(UpcomingReminder {
+                reminder: reminder.clone(),
+                fire_at: fire_at.with_timezone(&Utc),
+                source: UpcomingSource::Scheduled,
+            });
+        }
+    }
+
+    let snoozed: Vec<(String, DateTime<Utc>)> = SNOOZE_QUEUE.lock().await.values()
+        .map(|item| (item.reminder_uuid.clone(), item.snooze_until))
+        .collect();
+    let looping: Vec<(String, DateTime<Utc>)> = LOOP_QUEUE.lock().await.values()
+        .map(|item| (item.reminder_uuid.clone(), item.next_loop_at))
+        .collect();
+
+    let queued = snoozed.into_iter()
+        .map(|(uuid, fire_at)| (uuid, fire_at, UpcomingSource::Snoozed))
+        .chain(looping.into_iter().map(|(uuid, fire_at)| (uuid, fire_at, UpcomingSource::Loop)));
+
+    for (uuid, fire_at, source) in queued {
+        if let Some(reminder) = reminders.iter().find(|r| r.uuid == uuid) {
+            upcoming.push(UpcomingReminder {
+                reminder: reminder.clone(),
+                fire_at,
+                source,
+            });
+        }
+    }
+
+    upcoming.sort_by_key(|item| item.fire_at);
+    upcoming.truncate(limit);
+
+    Ok(upcoming)
+}
+
+/// 更新托盘提示为下一个提醒及剩余时间
+async fn update_tray_tooltip(app: &AppHandle) {
+    let app_name = if dnd::is_active_now() {
+        "叮咚 - 提醒助手（勿扰中）"
+    } else {
+        "叮咚 - 提醒助手"
+    };
+    let tooltip = match get_upcoming_reminders(1).await {
+        Ok(upcoming) => match upcoming.first() {
+            Some(next) => format!(
+                "{}\n下一个: {} ({}后)",
+                app_name,
+                next.reminder.title,
+                format_remaining(next.fire_at.signed_duration_since(Utc::now()))
+            ),
+            None => app_name.to_string(),
+        },
+        Err(e) => {
+            eprintln!("[调度器] 获取下一个提醒失败: {}", e);
+            return;
+        }
+    };
+
+    tray::set_tray_tooltip(app, &tooltip);
+}
+
+/// 触发所有已到期的常规提醒，并计算它们的下次触发时间
+async fn fire_due_reminders(app: &AppHandle) {
+    let now = Utc::now();
+    let mut due = vec![];
+
+    {
+        let mut schedule = SCHEDULE.lock().await;
+        while schedule.peek().map_or(false, |Reverse(fire)| fire.fire_at <= now) {
+            if let Some(Reverse(fire)) = schedule.pop() {
+                due.push(fire);
+            }
+        }
+    }
+
+    for fire in due {
+        let reminder = match reminder_repo::get_by_uuid(&fire.reminder_uuid) {
+            Ok(Some(reminder)) if reminder.is_enabled => reminder,
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("[调度器] 获取提醒失败: {}", e);
+                continue;
+            }
+        };
 
-    // 获取当月最后一天
-    let last_day_of_month = get_last_day_of_month(now.year(), now.month());
+        println!("[调度器] 触发提醒: {}", reminder.title);
+        let slot = occurrence::slot_of(&reminder, fire.fire_at.with_timezone(&Local));
+        trigger_reminder(app, &reminder, slot).await;
+
+        // 重新读取（触发时间已更新），计算下一次触发
+        if let Ok(Some(reminder)) = reminder_repo::get_by_uuid(&fire.reminder_uuid) {
+            match compute_next_fire(&reminder, fire.fire_at.with_timezone(&Local)) {
+                Some(fire_at) => {
+                    SCHEDULE.lock().await.push(Reverse(ScheduledFire {
+                        fire_at,
+                        reminder_uuid: reminder.uuid,
+                    }));
+                }
+                // 指定日期的单次提醒触发后即结束，达到结束日期或最大触发次数的提醒到期
+                None => {
+                    disable_finished_once(&reminder);
+                    expire_if_finished(&reminder).await;
+                }
+            }
+        }
+    }
+}
+
+/// 补发错过的提醒（启动时及检测到时钟跳变后调用）
+///
+/// 每个提醒只补发最近一次错过的触发，按设置重新触发、仅显示弹窗或仅记录日志。
+async fn catch_up_missed_reminders(app: &AppHandle) {
+    let now = Local::now();
+    let mode = settings_repo::get_all()
+        .map(|s| s.missed_reminder_mode)
+        .unwrap_or_default();
 
     let reminders = match reminder_repo::get_enabled_reminders() {
         Ok(r) => r,
@@ -147,77 +680,140 @@ async fn check_reminders(app: &AppHandle) {
         }
     };
 
-    println!("[调度器] 当前时间: {}, 找到 {} 个启用的提醒", current_time, reminders.len());
+    let mut missed = vec![];
+    let mut handled = vec![];
 
     for reminder in reminders {
-        println!("[调度器]   - {} (设定时间: {})", reminder.title, reminder.remind_time);
+        // 已达到最大触发次数的提醒不再补发（稍后重建计划时自动到期）
+        if remaining_occurrences(&reminder) == Some(0) {
+            continue;
+        }
 
-        if reminder.remind_time != current_time {
+        // 从上次触发、修改或暂停截止之后开始计算，避免刚修改或暂停中的提醒被当作错过
+        let since = [reminder.last_triggered_at, reminder.paused_until]
+            .into_iter()
+            .flatten()
+            .fold(reminder.updated_at, |since, t| since.max(t))
+            .with_timezone(&Local);
+
+        let Some(occurrence) = occurrence::last_occurrence_between(&reminder, since, now, MAX_CATCH_UP_DAYS) else {
+            continue;
+        };
+
+        // 刚好赶上的提醒按正常方式触发
+        if now.signed_duration_since(occurrence).num_seconds() < MISSED_GRACE_SECS {
+            println!("[调度器] 触发提醒: {}", reminder.title);
+            let slot = occurrence::slot_of(&reminder, occurrence);
+            trigger_reminder(app, &reminder, slot).await;
+            handled.push(reminder.uuid);
             continue;
         }
 
-        println!("[调度器]   >>> 时间匹配！检查是否今天已触发...");
+        handled.push(reminder.uuid.clone());
+        missed.push((reminder, occurrence));
+    }
+
+    if !missed.is_empty() {
+        handle_missed(app, &missed, mode).await;
+    }
 
-        // 检查今天是否已经触发过（避免重复触发）
-        if let Some(ref last_triggered) = reminder.last_triggered_at {
-            let last_triggered_date = last_triggered.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string();
-            if last_triggered_date == today {
-                println!("[调度器]   >>> 今天已触发过，跳过");
-                continue;
+    // 指定日期的单次提醒补发（或按设置忽略）后即结束
+    for uuid in handled {
+        if let Ok(Some(reminder)) = reminder_repo::get_by_uuid(&uuid) {
+            disable_finished_once(&reminder);
+        }
+    }
+}
+
+/// 按设置的补发方式处理错过的提醒
+async fn handle_missed(app: &AppHandle, missed: &[(Reminder, DateTime<Local>)], mode: MissedReminderMode) {
+    println!("[调度器] 发现 {} 个错过的提醒，补发方式: {:?}", missed.len(), mode);
+
+    for (reminder, occurrence) in missed {
+        if let Err(e) = reminder_log_repo::create(
+            &reminder.uuid,
+            LogAction::Missed,
+            occurrence.with_timezone(&Utc),
+            None,
+            Some(format!("错过的提醒，原定 {}", occurrence.format("%Y-%m-%d %H:%M"))),
+        ) {
+            eprintln!("Failed to create missed log: {}", e);
+        }
+    }
+
+    match mode {
+        MissedReminderMode::FireLate => {
+            for (reminder, occurrence) in missed {
+                let slot = occurrence::slot_of(reminder, *occurrence);
+                trigger_reminder(app, reminder, slot).await;
             }
         }
+        MissedReminderMode::Summary => {
+            for (reminder, _) in missed {
+                if let Err(e) = reminder_repo::update_triggered_at(&reminder.uuid) {
+                    eprintln!("Failed to update triggered_at: {}", e);
+                }
+            }
 
-        let should_trigger = match reminder.remind_type {
-            RemindType::Daily => true,
-            RemindType::Once => reminder.last_triggered_at.is_none(),
-            RemindType::Weekday => {
-                reminder.weekdays
-                    .as_ref()
-                    .map(|days| days.contains(&current_weekday))
-                    .unwrap_or(false)
+            // 勿扰期间只保留重要提醒，其余等勿扰结束后一并投递
+            let muted = dnd::is_active_now();
+            let mut uuids = vec![];
+            for (reminder, occurrence) in missed {
+                if muted && !reminder.is_critical {
+                    add_deferred(&reminder.uuid, occurrence.with_timezone(&Utc)).await;
+                } else {
+                    uuids.push(reminder.uuid.clone());
+                }
             }
-            RemindType::Monthly => {
-                reminder.monthdays
-                    .as_ref()
-                    .map(|days| {
-                        // 直接匹配当前日期
-                        if days.contains(&current_day) {
-                            return true;
-                        }
-                        // 如果今天是当月最后一天，检查是否有设置超过当月天数的日期
-                        // 例如：设置了31日，但2月只有28天，则在28日触发
-                        if current_day == last_day_of_month {
-                            days.iter().any(|&d| d > last_day_of_month)
-                        } else {
-                            false
-                        }
-                    })
-                    .unwrap_or(false)
+            if uuids.is_empty() {
+                return;
             }
-        };
 
-        if should_trigger {
-            println!("[调度器]   >>> 触发提醒: {}", reminder.title);
-            trigger_reminder(app, &reminder).await;
+            tray::start_tray_flash(app).await;
+            popup::show_reminder_popups(app, &uuids);
+        }
+        MissedReminderMode::Ignore => {
+            for (reminder, _) in missed {
+                if let Err(e) = reminder_repo::update_triggered_at(&reminder.uuid) {
+                    eprintln!("Failed to update triggered_at: {}", e);
+                }
+            }
         }
     }
 }
+/// 勿扰结束后，逐个弹窗投递勿扰期间暂缓的提醒
+///
+/// 调度器每次唤醒（最长间隔 `MAX_SLEEP`）都会检查，勿扰时段结束后最多延迟一分钟投递。
+async fn deliver_deferred_reminders(app: &AppHandle) {
+    if DEFERRED_QUEUE.lock().await.is_empty() || dnd::is_active_now() {
+        return;
+    }
 
-/// 获取指定年月的最后一天
-fn get_last_day_of_month(year: i32, month: u32) -> u8 {
-    use chrono::NaiveDate;
-
-    // 获取下个月的第一天，然后减一天
-    let next_month = if month == 12 {
-        NaiveDate::from_ymd_opt(year + 1, 1, 1)
-    } else {
-        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    let deferred: Vec<String> = {
+        let mut queue = DEFERRED_QUEUE.lock().await;
+        let mut items: Vec<(String, DateTime<Utc>)> = queue.drain().collect();
+        items.sort_by_key(|(_, triggered_at)| *triggered_at);
+        items.into_iter().map(|(uuid, _)| uuid).collect()
     };
 
-    next_month
-        .and_then(|d| d.pred_opt())
-        .map(|d| d.day() as u8)
-        .unwrap_or(28) // fallback
+    let mut uuids = vec![];
+    for uuid in deferred {
+        unpersist_queue_item(QueueType::Deferred, &uuid);
+        match reminder_repo::get_by_uuid(&uuid) {
+            Ok(Some(_)) => uuids.push(uuid),
+            Ok(None) => {}
+            Err(e) => eprintln!("[调度器] 获取提醒失败: {}", e),
+        }
+    }
+
+    if uuids.is_empty() {
+        return;
+    }
+
+    println!("[调度器] 勿扰已结束，投递 {} 个暂缓的提醒", uuids.len());
+    tray::start_tray_flash(app).await;
+    // 弹窗可能排队等待，超时检测和循环提醒在弹窗显示时才开始
+    popup::show_deferred_popups(app, &uuids);
 }
 
 /// 检查延迟队列
@@ -245,6 +841,7 @@ async fn check_snooze_queue(app: &AppHandle) {
 
         for uuid in expired {
             if let Some(item) = queue.remove(&uuid) {
+                unpersist_queue_item(QueueType::Snooze, &uuid);
                 println!("[调度器] 延迟提醒到期: {}", uuid);
                 to_trigger.push(item);
             }
@@ -253,9 +850,9 @@ async fn check_snooze_queue(app: &AppHandle) {
 
     for item in to_trigger {
         if let Ok(Some(reminder)) = reminder_repo::get_by_uuid(&item.reminder_uuid) {
-            if reminder.is_enabled {
+            if reminder.is_enabled || has_finished(&reminder) {
                 println!("[调度器] 触发延迟提醒: {}", reminder.title);
-                trigger_reminder_internal(app, &reminder, true).await;
+                trigger_reminder_internal(app, &reminder, true, None).await;
             } else {
                 println!("[调度器] 延迟提醒已禁用，跳过: {}", reminder.title);
             }
@@ -279,6 +876,7 @@ async fn check_loop_queue(app: &AppHandle) {
 
         for uuid in expired {
             if let Some(item) = queue.remove(&uuid) {
+                unpersist_queue_item(QueueType::Loop, &uuid);
                 to_trigger.push(item);
             }
         }
@@ -286,9 +884,9 @@ async fn check_loop_queue(app: &AppHandle) {
 
     for item in to_trigger {
         if let Ok(Some(reminder)) = reminder_repo::get_by_uuid(&item.reminder_uuid) {
-            if reminder.is_enabled {
+            if reminder.is_enabled || has_finished(&reminder) {
                 // 循环提醒继续触发
-                trigger_reminder_internal(app, &reminder, true).await;
+                trigger_reminder_internal(app, &reminder, true, None).await;
 
                 // 如果还是循环模式，重新加入队列
                 if reminder.is_loop {
@@ -318,6 +916,7 @@ async fn check_timeout_queue(app: &AppHandle) {
 
         for uuid in expired {
             queue.remove(&uuid);
+            unpersist_queue_item(QueueType::Timeout, &uuid);
             timed_out.push(uuid);
         }
     }
@@ -331,26 +930,35 @@ async fn check_timeout_queue(app: &AppHandle) {
 
 /// 处理超时
 async fn handle_timeout(app: &AppHandle, reminder: &Reminder) {
+    // 记录超时日志（摘要统计超时次数），未设置超时渠道时同样记录
+    let triggered_at = reminder.last_triggered_at.unwrap_or_else(Utc::now);
+    if let Err(e) = reminder_log_repo::create(&reminder.uuid, LogAction::TimedOut, triggered_at, None, None) {
+        eprintln!("[调度器] 记录超时日志失败: {} ({})", reminder.uuid, e);
+    }
+
+    if reminder.timeout_channels.is_empty() {
+        return;
+    }
+
     // 发送超时事件到前端
     let _ = app.emit("reminder-timeout", reminder.clone());
 
-    // 发送 Server酱通知
-    if reminder.notify_on_timeout {
-        send_serverchan_notification(
-            &format!("提醒超时: {}", reminder.title),
-            &format!("提醒「{}」已超时未完成", reminder.title),
-        ).await;
-    }
+    // 推送超时通知
+    let muted = !reminder.is_critical && dnd::is_active_now();
+    channel::dispatch(reminder.timeout_channels.clone(), NotificationMessage::timeout(reminder), muted);
 }
 
 /// 触发提醒
-async fn trigger_reminder(app: &AppHandle, reminder: &Reminder) {
-    trigger_reminder_internal(app, reminder, false).await;
+async fn trigger_reminder(app: &AppHandle, reminder: &Reminder, slot: Option<String>) {
+    trigger_reminder_internal(app, reminder, false, slot).await;
 }
 
 /// 触发提醒内部实现
-async fn trigger_reminder_internal(app: &AppHandle, reminder: &Reminder, is_repeat: bool) {
+async fn trigger_reminder_internal(app: &AppHandle, reminder: &Reminder, is_repeat: bool, slot: Option<String>) {
     let now = Utc::now();
+    let settings = settings_repo::get_all().unwrap_or_default();
+    // 勿扰期间（重要提醒除外）不弹窗、不闪动，照常记录触发
+    let muted = !reminder.is_critical && dnd::is_active(&settings, Local::now());
 
     // 打印日志，确认任务被触发
     println!("========================================");
@@ -358,7 +966,9 @@ async fn trigger_reminder_internal(app: &AppHandle, reminder: &Reminder, is_repe
     println!("========================================");
 
     // 托盘图标闪动
-    tray::start_tray_flash(app).await;
+    if !muted {
+        tray::start_tray_flash(app).await;
+    }
 
     // 更新触发时间
     if !is_repeat {
@@ -371,27 +981,51 @@ async fn trigger_reminder_internal(app: &AppHandle, reminder: &Reminder, is_repe
     if let Err(e) = reminder_log_repo::create_triggered(
         &reminder.uuid,
         if is_repeat { Some("重复提醒".to_string()) } else { None },
+        slot,
     ) {
         eprintln!("Failed to create trigger log: {}", e);
     }
 
-    // 显示独立弹窗（在屏幕右下角/右上角）
-    println!("[提醒触发] 正在创建弹窗窗口: {}", reminder.uuid);
-    match popup::show_reminder_popup(app, &reminder.uuid) {
-        Ok(_) => println!("[提醒触发] 弹窗创建成功"),
-        Err(e) => {
-            eprintln!("[提醒触发] 弹窗创建失败: {}", e);
-            // 如果弹窗失败，回退到显示主窗口
-            if let Some(window) = app.get_webview_window("main") {
-                let _ = window.show();
-                let _ = window.set_focus();
+    if muted {
+        // 勿扰结束后合并投递，超时检测和循环提醒届时再开始
+        println!("[提醒触发] 勿扰中，暂缓弹窗: {}", reminder.uuid);
+        add_deferred(&reminder.uuid, now).await;
+    } else {
+        // 显示独立弹窗（在屏幕右下角/右上角）
+        println!("[提醒触发] 正在创建弹窗窗口: {}", reminder.uuid);
+        match popup::show_reminder_popup(app, &reminder.uuid) {
+            Ok(_) => println!("[提醒触发] 弹窗创建成功"),
+            Err(e) => {
+                eprintln!("[提醒触发] 弹窗创建失败: {}", e);
+                // 如果弹窗失败，回退到显示主窗口
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
             }
         }
+
+        schedule_follow_ups(reminder, now).await;
+    }
+
+    // 推送触发通知（勿扰期间只发送允许勿扰时发送的渠道）
+    channel::dispatch(reminder.trigger_channels.clone(), NotificationMessage::trigger(reminder), muted);
+}
+
+/// 暂缓的提醒弹窗显示后开始超时检测和循环提醒
+pub async fn arm_follow_ups(reminder_uuid: String) {
+    match reminder_repo::get_by_uuid(&reminder_uuid) {
+        Ok(Some(reminder)) => schedule_follow_ups(&reminder, Utc::now()).await,
+        Ok(None) => {}
+        Err(e) => eprintln!("[调度器] 获取提醒失败: {}", e),
     }
+}
 
+/// 弹窗后开始超时检测和循环提醒
+async fn schedule_follow_ups(reminder: &Reminder, now: DateTime<Utc>) {
     // 如果有超时设置，加入超时检测队列
     if let Some(timeout_minutes) = reminder.timeout_minutes {
-        if timeout_minutes > 0 && reminder.notify_on_timeout {
+        if timeout_minutes > 0 {
             add_timeout_check(&reminder.uuid, timeout_minutes).await;
         }
     }
@@ -402,36 +1036,76 @@ async fn trigger_reminder_internal(app: &AppHandle, reminder: &Reminder, is_repe
             add_loop(&reminder.uuid, interval, now).await;
         }
     }
+}
 
-    // 发送 Server酱通知
-    if reminder.notify_on_trigger {
-        send_serverchan_notification(
-            &format!("提醒触发: {}", reminder.title),
-            &reminder.content,
-        ).await;
+/// 设置了结束日期或最大触发次数的提醒到期后自动禁用，并记录日志
+///
+/// 仍有延迟或循环提醒待处理时暂不禁用，避免最后一次提醒被吞掉。返回是否已禁用。
+async fn expire_if_finished(reminder: &Reminder) -> bool {
+    if reminder.ends_on.is_none() && reminder.max_occurrences.is_none() {
+        return false;
     }
-}
 
-/// 发送 Server酱通知
-async fn send_serverchan_notification(title: &str, content: &str) {
-    let (domain, sendkey) = match settings_repo::get_all() {
-        Ok(settings) => (settings.serverchan_domain, settings.serverchan_sendkey),
-        Err(_) => (None, None),
+    let note = if let Some(max) = reminder.max_occurrences.filter(|_| remaining_occurrences(reminder) == Some(0)) {
+        format!("已触发 {} 次，达到最大触发次数", max)
+    } else if compute_next_fire(reminder, Local::now()).is_none() && reminder.ends_on.is_some() {
+        format!("已过结束日期 {}", reminder.ends_on.map(|d| d.to_string()).unwrap_or_default())
+    } else {
+        return false;
     };
 
-    if let Some(key) = sendkey {
-        if !key.is_empty() {
-            if let Err(e) = serverchan::send_notification(domain.as_deref(), &key, title, content).await {
-                eprintln!("Failed to send Server酱 notification: {}", e);
-            }
+    if SNOOZE_QUEUE.lock().await.contains_key(&reminder.uuid)
+        || LOOP_QUEUE.lock().await.contains_key(&reminder.uuid)
+    {
+        return false;
+    }
+
+    if let Err(e) = reminder_repo::set_enabled(&reminder.uuid, false) {
+        eprintln!("Failed to disable expired reminder: {}", e);
+        return false;
+    }
+    if let Err(e) = reminder_log_repo::create(&reminder.uuid, LogAction::Expired, Utc::now(), None, Some(note.clone())) {
+        eprintln!("Failed to create expired log: {}", e);
+    }
+    println!("[调度器] 提醒已到期，自动禁用: {}（{}）", reminder.title, note);
+    true
+}
+
+/// 指定日期的单次提醒是否已触发过
+fn is_fired_once(reminder: &Reminder) -> bool {
+    reminder.remind_type == RemindType::Once
+        && reminder.remind_date.is_some()
+        && reminder.last_triggered_at.is_some()
+}
+
+/// 提醒是否已自然结束（指定日期的单次提醒已触发、达到最大触发次数或已过结束日期）
+///
+/// 结束的提醒会自动禁用，但已在进行的延迟、循环提醒照常进行。
+fn has_finished(reminder: &Reminder) -> bool {
+    is_fired_once(reminder)
+        || remaining_occurrences(reminder) == Some(0)
+        || (reminder.ends_on.is_some() && compute_next_fire(reminder, Local::now()).is_none())
+}
+
+/// 指定日期的单次提醒触发后自动禁用
+fn disable_finished_once(reminder: &Reminder) {
+    if !reminder.is_enabled || !is_fired_once(reminder) {
+        return;
+    }
+
+    match reminder_repo::set_enabled(&reminder.uuid, false) {
+        Ok(_) => {
+            println!("[调度器] 单次提醒已完成，自动禁用: {}", reminder.title);
+            notify_reminders_changed();
         }
+        Err(e) => eprintln!("Failed to disable finished reminder: {}", e),
     }
 }
 
 /// 完成提醒（供外部调用）
-pub async fn complete_reminder_action(app: &AppHandle, uuid: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+pub async fn complete_reminder_action(app: &AppHandle, uuid: &str) -> AppResult<()> {
     let reminder = reminder_repo::get_by_uuid(uuid)?
-        .ok_or("Reminder not found")?;
+        .ok_or_else(|| AppError::reminder_not_found(uuid))?;
 
     // 更新完成时间
     reminder_repo::update_completed_at(uuid)?;
@@ -449,15 +1123,17 @@ pub async fn complete_reminder_action(app: &AppHandle, uuid: &str) -> Result<(),
     remove_loop(uuid).await;
     remove_snooze(uuid).await;
     remove_timeout_check(uuid).await;
+    remove_deferred(uuid).await;
 
-    // 发送完成通知
-    if reminder.notify_on_complete {
-        send_serverchan_notification(
-            &format!("提醒完成: {}", reminder.title),
-            &format!("提醒「{}」已完成", reminder.title),
-        ).await;
+    // 指定日期的单次提醒到此结束
+    disable_finished_once(&reminder);
+    if expire_if_finished(&reminder).await {
+        notify_reminders_changed();
     }
 
+    // 推送完成通知
+    channel::dispatch(reminder.complete_channels.clone(), NotificationMessage::complete(&reminder), false);
+
     // 发送完成事件到前端
     let _ = app.emit("reminder-completed", uuid.to_string());
 
@@ -469,7 +1145,7 @@ pub async fn snooze_reminder_action(
     _app: &AppHandle,
     uuid: &str,
     minutes: i32,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+) -> AppResult<()> {
     let now = Utc::now();
     let snooze_until = now + chrono::Duration::minutes(minutes as i64);
 
@@ -488,6 +1164,9 @@ pub async fn snooze_reminder_action(
     // 从超时队列移除（重新计时）
     remove_timeout_check(uuid).await;
 
+    // 从勿扰暂缓队列移除（延迟后的提醒仍受勿扰限制）
+    remove_deferred(uuid).await;
+
     // 加入延迟队列
     add_snooze(uuid, minutes, now).await;
 
@@ -498,9 +1177,9 @@ pub async fn snooze_reminder_action(
 pub async fn dismiss_reminder_action(
     app: &AppHandle,
     uuid: &str,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+) -> AppResult<()> {
     let reminder = reminder_repo::get_by_uuid(uuid)?
-        .ok_or("Reminder not found")?;
+        .ok_or_else(|| AppError::reminder_not_found(uuid))?;
 
     let now = Utc::now();
 
@@ -519,6 +1198,9 @@ pub async fn dismiss_reminder_action(
     // 从超时队列移除
     remove_timeout_check(uuid).await;
 
+    // 从勿扰暂缓队列移除
+    remove_deferred(uuid).await;
+
     // 处理 repeat_on_close 逻辑
     if reminder.repeat_on_close {
         if let Some(interval) = reminder.repeat_interval {
@@ -528,6 +1210,12 @@ pub async fn dismiss_reminder_action(
     } else {
         // 如果不是 repeat_on_close，也从循环队列移除
         remove_loop(uuid).await;
+
+        // 指定日期的单次提醒到此结束
+        disable_finished_once(&reminder);
+        if expire_if_finished(&reminder).await {
+            notify_reminders_changed();
+        }
     }
 
     // 发送关闭事件到前端
@@ -1 +1,7 @@
+pub mod dnd;
+pub mod holiday_calendar;
+pub mod interval;
 pub mod manager;
+pub mod occurrence;
+pub mod recurrence_rule;
+pub mod zone;
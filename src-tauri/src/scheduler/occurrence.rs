@@ -0,0 +1,526 @@
+use chrono::{Datelike, DateTime, Local, NaiveDate, NaiveTime};
+
+use crate::models::reminder::{LeapDayPolicy, LunarLeapPolicy, MonthlyMode, Reminder, RemindType};
+use crate::scheduler::{holiday_calendar, interval};
+use crate::scheduler::zone::ReminderZone;
+use crate::scheduler::recurrence_rule::RecurrenceRule;
+use crate::utils::lunar;
+use crate::utils::time::parse_time;
+
+/// 计算下次触发时间时最多向后查找的天数
+const MAX_LOOKAHEAD_DAYS: i64 = 400;
+
+/// 每年提醒跳过 2 月 29 日时可能要等 8 年（跨过 2100 年这样的非闰年）
+const MAX_YEARLY_LOOKAHEAD_DAYS: i64 = 366 * 8 + 1;
+
+/// 判断提醒在指定日期是否应该触发（不考虑时间）
+pub fn should_trigger_on(reminder: &Reminder, date: NaiveDate) -> bool {
+    let weekday = date.weekday().num_days_from_monday() as u8 + 1;
+    let day = date.day() as u8;
+
+    // 设置了跳过法定节假日
+    if reminder.skip_holidays && holiday_calendar::is_holiday(date) {
+        return false;
+    }
+
+    match reminder.remind_type {
+        RemindType::Daily => true,
+        RemindType::Once => {
+            reminder.last_triggered_at.is_none()
+                && reminder.remind_date.map_or(true, |d| d == date)
+        }
+        RemindType::Weekday => {
+            reminder.weekdays
+                .as_ref()
+                .map(|days| days.contains(&weekday))
+                .unwrap_or(false)
+        }
+        RemindType::Monthly => {
+            // 获取当月最后一天
+            let last_day_of_month = get_last_day_of_month(date.year(), date.month());
+
+            match reminder.monthly_mode.clone().unwrap_or_default() {
+                MonthlyMode::Days => {}
+                MonthlyMode::NthWeekday => {
+                    // 第 N 个星期几：当月第 1-7 天为第一个，依此类推（当月没有第 5 个时不触发）
+                    return reminder.month_weekday == Some(weekday)
+                        && reminder.month_week == Some((day - 1) / 7 + 1);
+                }
+                MonthlyMode::LastWeekday => {
+                    return reminder.month_weekday == Some(weekday)
+                        && day + 7 > last_day_of_month;
+                }
+                MonthlyMode::LastDay => return day == last_day_of_month,
+                MonthlyMode::DaysBeforeEnd => {
+                    return reminder.days_before_end
+                        .map_or(false, |before| before < last_day_of_month && day == last_day_of_month - before);
+                }
+            }
+
+            reminder.monthdays
+                .as_ref()
+                .map(|days| {
+                    // 直接匹配当前日期
+                    if days.contains(&day) {
+                        return true;
+                    }
+                    // 如果今天是当月最后一天，检查是否有设置超过当月天数的日期
+                    // 例如：设置了31日，但2月只有28天，则在28日触发
+                    if day == last_day_of_month {
+                        days.iter().any(|&d| d > last_day_of_month)
+                    } else {
+                        false
+                    }
+                })
+                .unwrap_or(false)
+        }
+        RemindType::Yearly => matches_yearly(reminder, date),
+        RemindType::Advanced => {
+            parse_rule(reminder)
+                .map(|rule| rule.matches_date(rule_start(reminder), date, &ReminderZone::of(reminder)))
+                .unwrap_or(false)
+        }
+        RemindType::Interval => {
+            // 当天是否存在间隔触发点
+            let zone = ReminderZone::of(reminder);
+            zone.start_of(date)
+                .and_then(|start| interval::next_after(reminder, start - chrono::Duration::seconds(1), 1))
+                .map_or(false, |occurrence| zone.date(occurrence) == date)
+        }
+        RemindType::ChinaWorkday => holiday_calendar::is_china_workday(date),
+        RemindType::Lunar => matches_lunar(reminder, date),
+    }
+}
+
+/// 每年提醒：当年没有设置的日期时按 `leap_day_policy` 处理（默认在当月最后一天触发）
+fn matches_yearly(reminder: &Reminder, date: NaiveDate) -> bool {
+    let (Some(month), Some(day)) = (reminder.yearly_month, reminder.yearly_day) else {
+        return false;
+    };
+    if date.month() == month as u32 && date.day() == day as u32 {
+        return true;
+    }
+
+    let last_day_of_month = get_last_day_of_month(date.year(), month as u32);
+    if day <= last_day_of_month {
+        return false;
+    }
+
+    match reminder.leap_day_policy.clone().unwrap_or_default() {
+        LeapDayPolicy::LastDay => date.month() == month as u32 && date.day() == last_day_of_month as u32,
+        LeapDayPolicy::NextDay => {
+            NaiveDate::from_ymd_opt(date.year(), month as u32, last_day_of_month as u32)
+                .and_then(|last| last.succ_opt())
+                == Some(date)
+        }
+        LeapDayPolicy::Skip => false,
+    }
+}
+
+/// 农历提醒：提前天数之后的日期是否为设置的农历日期
+fn matches_lunar(reminder: &Reminder, date: NaiveDate) -> bool {
+    let Some(lunar_day) = reminder.lunar_day else {
+        return false;
+    };
+    let days_before = reminder.lunar_days_before.unwrap_or(0).max(0) as i64;
+    let Some(target) = date.checked_add_signed(chrono::Duration::days(days_before))
+        .and_then(lunar::from_solar)
+    else {
+        return false;
+    };
+
+    if reminder.lunar_month.map_or(false, |month| month != target.month) {
+        return false;
+    }
+
+    let month_matches = match reminder.lunar_leap_policy.clone().unwrap_or_default() {
+        LunarLeapPolicy::Regular => !target.is_leap,
+        LunarLeapPolicy::Both => true,
+        LunarLeapPolicy::PreferLeap => {
+            // 当年有对应闰月时只在闰月触发
+            target.is_leap || lunar::leap_month(target.year) != Some(target.month)
+        }
+    };
+    if !month_matches {
+        return false;
+    }
+
+    // 小月没有三十时在当月最后一天触发
+    lunar::month_days(target.year, target.month, target.is_leap)
+        .map_or(false, |days| target.day == lunar_day.min(days))
+}
+
+/// 跳过落在法定节假日的触发时间（用于不按天计算的 cron / 间隔提醒），向后查找
+fn skip_holidays_after(
+    reminder: &Reminder,
+    after: DateTime<Local>,
+    next: impl Fn(DateTime<Local>) -> Option<DateTime<Local>>,
+) -> Option<DateTime<Local>> {
+    let zone = ReminderZone::of(reminder);
+    let mut cursor = after;
+    for _ in 0..MAX_LOOKAHEAD_DAYS {
+        let occurrence = next(cursor)?;
+        let date = zone.date(occurrence);
+        if !reminder.skip_holidays || !holiday_calendar::is_holiday(date) {
+            return Some(occurrence);
+        }
+        // 直接跳到节假日当天结束
+        cursor = zone.start_of(date.succ_opt()?)? - chrono::Duration::seconds(1);
+    }
+    None
+}
+
+/// 跳过落在法定节假日的触发时间，向前查找
+fn skip_holidays_before(
+    reminder: &Reminder,
+    until: DateTime<Local>,
+    last: impl Fn(DateTime<Local>) -> Option<DateTime<Local>>,
+) -> Option<DateTime<Local>> {
+    let zone = ReminderZone::of(reminder);
+    let mut cursor = until;
+    for _ in 0..MAX_LOOKAHEAD_DAYS {
+        let occurrence = last(cursor)?;
+        let date = zone.date(occurrence);
+        if !reminder.skip_holidays || !holiday_calendar::is_holiday(date) {
+            return Some(occurrence);
+        }
+        // 直接跳到节假日前一天结束
+        cursor = zone.start_of(date)? - chrono::Duration::seconds(1);
+    }
+    None
+}
+
+/// 解析提醒的高级重复规则（无效规则视为不触发），RRULE 未指定 DTSTART 时从 `rule_start` 开始
+fn parse_rule(reminder: &Reminder) -> Option<RecurrenceRule> {
+    reminder.recurrence_rule
+        .as_deref()
+        .and_then(|rule| RecurrenceRule::parse(rule).ok())
+        .map(|rule| rule.with_start(rule_start(reminder)))
+}
+
+/// 高级规则的起始日期：优先使用 remind_date，否则为创建日期
+fn rule_start(reminder: &Reminder) -> NaiveDate {
+    reminder.remind_date
+        .unwrap_or_else(|| ReminderZone::of(reminder).date(reminder.created_at.with_timezone(&Local)))
+}
+
+/// 获取提醒的 cron 规则（cron 表达式自带触发时间，不使用 remind_time）
+fn cron_rule(reminder: &Reminder) -> Option<RecurrenceRule> {
+    if reminder.remind_type != RemindType::Advanced {
+        return None;
+    }
+    parse_rule(reminder).filter(|rule| rule.has_time())
+}
+
+/// 解析提醒每天的触发时间点（按时间排序、去重）
+fn slot_times(reminder: &Reminder) -> Vec<NaiveTime> {
+    let mut times: Vec<NaiveTime> = reminder.time_slots()
+        .into_iter()
+        .filter_map(parse_time)
+        .collect();
+    times.sort();
+    times.dedup();
+    times
+}
+
+/// 获取触发时间对应的时间点（原始设置的字符串，如 "14:00"）
+pub fn slot_of(reminder: &Reminder, fire_at: DateTime<Local>) -> Option<String> {
+    let time = ReminderZone::of(reminder).naive(fire_at).time();
+    reminder.time_slots()
+        .into_iter()
+        .find(|slot| parse_time(slot) == Some(time))
+        .map(|slot| slot.to_string())
+}
+
+/// 获取指定年月的最后一天
+pub fn get_last_day_of_month(year: i32, month: u32) -> u8 {
+    // 获取下个月的第一天，然后减一天
+    let next_month = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+
+    next_month
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day() as u8)
+        .unwrap_or(28) // fallback
+}
+
+/// 生效日期范围对应的触发时刻范围 [开始, 结束]
+fn active_range(reminder: &Reminder, zone: &ReminderZone) -> (Option<DateTime<Local>>, Option<DateTime<Local>>) {
+    let start = reminder.starts_on.and_then(|date| zone.start_of(date));
+    let end = reminder.ends_on
+        .and_then(|date| date.succ_opt())
+        .and_then(|date| zone.start_of(date))
+        .map(|next_day| next_day - chrono::Duration::seconds(1));
+    (start, end)
+}
+
+/// 计算提醒在 `after` 之后（不含）的下一次触发时间（限制在生效日期范围内）
+pub fn next_occurrence(reminder: &Reminder, after: DateTime<Local>) -> Option<DateTime<Local>> {
+    let zone = ReminderZone::of(reminder);
+    let (start, end) = active_range(reminder, &zone);
+
+    let after = match start {
+        Some(start) => after.max(start - chrono::Duration::seconds(1)),
+        None => after,
+    };
+    next_unbounded(reminder, after).filter(|next| end.map_or(true, |end| *next <= end))
+}
+
+/// 计算下一次触发时间（不考虑生效日期范围）
+fn next_unbounded(reminder: &Reminder, after: DateTime<Local>) -> Option<DateTime<Local>> {
+    let zone = ReminderZone::of(reminder);
+
+    if let Some(rule) = cron_rule(reminder) {
+        // cron 表达式在提醒的时区中计算
+        return skip_holidays_after(reminder, after, |cursor| match zone.tz() {
+            Some(tz) => rule.cron_next(cursor.with_timezone(&tz)).map(|dt| dt.with_timezone(&Local)),
+            None => rule.cron_next(cursor),
+        });
+    }
+    if reminder.remind_type == RemindType::Interval {
+        return skip_holidays_after(reminder, after, |cursor| {
+            interval::next_after(reminder, cursor, MAX_LOOKAHEAD_DAYS)
+        });
+    }
+
+    let times = slot_times(reminder);
+
+    // 指定日期的单次提醒直接计算，不受向后查找天数限制
+    if let (RemindType::Once, Some(date)) = (&reminder.remind_type, reminder.remind_date) {
+        if reminder.last_triggered_at.is_some() {
+            return None;
+        }
+        return times.iter()
+            .filter_map(|time| zone.at(date, *time))
+            .find(|occurrence| *occurrence > after);
+    }
+
+    let mut date = zone.date(after);
+    let lookahead_days = if reminder.remind_type == RemindType::Yearly {
+        MAX_YEARLY_LOOKAHEAD_DAYS
+    } else {
+        MAX_LOOKAHEAD_DAYS
+    };
+
+    for _ in 0..lookahead_days {
+        if should_trigger_on(reminder, date) {
+            for time in &times {
+                if let Some(occurrence) = zone.at(date, *time) {
+                    if occurrence > after {
+                        return Some(occurrence);
+                    }
+                }
+            }
+        }
+        date = date.succ_opt()?;
+    }
+
+    None
+}
+
+/// 计算提醒在 `after` 之后（不含）的最多 `count` 次触发时间
+pub fn upcoming_occurrences(reminder: &Reminder, after: DateTime<Local>, count: usize) -> Vec<DateTime<Local>> {
+    // 单次提醒最多只有一次
+    let count = if reminder.remind_type == RemindType::Once { count.min(1) } else { count };
+    let mut occurrences = Vec::with_capacity(count);
+    let mut cursor = after;
+
+    while occurrences.len() < count {
+        match next_occurrence(reminder, cursor) {
+            Some(occurrence) => {
+                occurrences.push(occurrence);
+                cursor = occurrence;
+            }
+            None => break,
+        }
+    }
+
+    occurrences
+}
+
+/// 查找提醒在 (since, until] 区间内最近一次应触发的时间，最多回溯 `max_days` 天
+pub fn last_occurrence_between(
+    reminder: &Reminder,
+    since: DateTime<Local>,
+    until: DateTime<Local>,
+    max_days: i64,
+) -> Option<DateTime<Local>> {
+    let zone = ReminderZone::of(reminder);
+    let (start, end) = active_range(reminder, &zone);
+    let since = start.map_or(since, |start| since.max(start - chrono::Duration::seconds(1)));
+    let until = end.map_or(until, |end| until.min(end));
+    if since >= until {
+        return None;
+    }
+
+    if let Some(rule) = cron_rule(reminder) {
+        let since = since.max(until - chrono::Duration::days(max_days));
+        return skip_holidays_before(reminder, until, |cursor| match zone.tz() {
+            Some(tz) => rule.cron_last_between(since.with_timezone(&tz), cursor.with_timezone(&tz))
+                .map(|dt| dt.with_timezone(&Local)),
+            None => rule.cron_last_between(since, cursor),
+        });
+    }
+    if reminder.remind_type == RemindType::Interval {
+        return skip_holidays_before(reminder, until, |cursor| {
+            interval::last_between(reminder, since, cursor, max_days)
+        });
+    }
+
+    let times = slot_times(reminder);
+    let earliest = zone.date(until - chrono::Duration::days(max_days));
+    let mut date = zone.date(until);
+
+    while date >= earliest && date >= zone.date(since) {
+        if should_trigger_on(reminder, date) {
+            for time in times.iter().rev() {
+                if let Some(occurrence) = zone.at(date, *time) {
+                    if occurrence > since && occurrence <= until {
+                        return Some(occurrence);
+                    }
+                }
+            }
+        }
+        date = date.pred_opt()?;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// 每天 09:00 的提醒，`fields` 覆盖默认字段
+    fn reminder(fields: serde_json::Value) -> Reminder {
+        let mut value = json!({
+            "uuid": "test", "title": "测试", "content": "", "remindTime": "09:00", "remindType": "daily",
+            "isCritical": false, "skipHolidays": false, "isEnabled": true, "repeatOnClose": false, "isLoop": false,
+            "triggerChannels": [], "completeChannels": [], "timeoutChannels": [], "sortOrder": 0,
+            "createdAt": "2026-01-01T00:00:00Z", "updatedAt": "2026-01-01T00:00:00Z",
+            "version": 1, "syncStatus": "pending"
+        });
+        for (key, field) in fields.as_object().unwrap() {
+            value[key] = field.clone();
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    /// 从 `from` 起（最多十年内）提醒触发的前 `n` 个日期
+    fn trigger_dates(reminder: &Reminder, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take(3660)
+            .filter(|d| should_trigger_on(reminder, *d))
+            .take(n)
+            .collect()
+    }
+
+    #[test]
+    fn lunar_leap_month_policies() {
+        // 2025 年闰六月：六月初一为 6 月 25 日，闰六月初一为 7 月 25 日
+        let lunar = |policy: &str| reminder(json!({
+            "remindType": "lunar", "lunarMonth": 6, "lunarDay": 1, "lunarLeapPolicy": policy
+        }));
+        let from = date(2025, 1, 1);
+        assert_eq!(trigger_dates(&lunar("regular"), from, 2), vec![date(2025, 6, 25), date(2026, 7, 14)]);
+        assert_eq!(trigger_dates(&lunar("prefer_leap"), from, 2), vec![date(2025, 7, 25), date(2026, 7, 14)]);
+        assert_eq!(trigger_dates(&lunar("both"), from, 3), vec![date(2025, 6, 25), date(2025, 7, 25), date(2026, 7, 14)]);
+    }
+
+    #[test]
+    fn lunar_short_month_and_days_before() {
+        // 腊月三十在小月时按当月最后一天（廿九）触发
+        let new_years_eve = reminder(json!({"remindType": "lunar", "lunarMonth": 12, "lunarDay": 30}));
+        assert_eq!(trigger_dates(&new_years_eve, date(2025, 1, 1), 2), vec![date(2025, 1, 28), date(2026, 2, 16)]);
+
+        let mid_autumn = reminder(json!({
+            "remindType": "lunar", "lunarMonth": 8, "lunarDay": 15, "lunarDaysBefore": 3
+        }));
+        assert_eq!(trigger_dates(&mid_autumn, date(2025, 1, 1), 2), vec![date(2025, 10, 3), date(2026, 9, 22)]);
+    }
+
+    #[test]
+    fn yearly_leap_day_policies() {
+        let leap_day = |policy: &str| reminder(json!({
+            "remindType": "yearly", "yearlyMonth": 2, "yearlyDay": 29, "leapDayPolicy": policy
+        }));
+        let from = date(2026, 10, 18);
+        assert_eq!(
+            trigger_dates(&leap_day("last_day"), from, 3),
+            vec![date(2027, 2, 28), date(2028, 2, 29), date(2029, 2, 28)]
+        );
+        assert_eq!(
+            trigger_dates(&leap_day("next_day"), from, 3),
+            vec![date(2027, 3, 1), date(2028, 2, 29), date(2029, 3, 1)]
+        );
+        assert_eq!(
+            trigger_dates(&leap_day("skip"), from, 3),
+            vec![date(2028, 2, 29), date(2032, 2, 29), date(2036, 2, 29)]
+        );
+    }
+
+    #[test]
+    fn yearly_regular_date() {
+        let tax_day = reminder(json!({"remindType": "yearly", "yearlyMonth": 4, "yearlyDay": 15}));
+        assert_eq!(trigger_dates(&tax_day, date(2026, 10, 18), 2), vec![date(2027, 4, 15), date(2028, 4, 15)]);
+    }
+
+    #[test]
+    fn monthly_ordinal_weekday_modes() {
+        let from = date(2026, 10, 18);
+        // 每月第二个周二
+        let second_tuesday = reminder(json!({
+            "remindType": "monthly", "monthlyMode": "nth_weekday", "monthWeek": 2, "monthWeekday": 2
+        }));
+        assert_eq!(
+            trigger_dates(&second_tuesday, from, 3),
+            vec![date(2026, 11, 10), date(2026, 12, 8), date(2027, 1, 12)]
+        );
+
+        // 没有第五个周五的月份不触发
+        let fifth_friday = reminder(json!({
+            "remindType": "monthly", "monthlyMode": "nth_weekday", "monthWeek": 5, "monthWeekday": 5
+        }));
+        assert_eq!(
+            trigger_dates(&fifth_friday, from, 3),
+            vec![date(2026, 10, 30), date(2027, 1, 29), date(2027, 4, 30)]
+        );
+
+        let last_friday = reminder(json!({"remindType": "monthly", "monthlyMode": "last_weekday", "monthWeekday": 5}));
+        assert_eq!(
+            trigger_dates(&last_friday, from, 3),
+            vec![date(2026, 10, 30), date(2026, 11, 27), date(2026, 12, 25)]
+        );
+    }
+
+    #[test]
+    fn monthly_last_day_modes() {
+        let from = date(2027, 1, 1);
+        let last_day = reminder(json!({"remindType": "monthly", "monthlyMode": "last_day"}));
+        assert_eq!(
+            trigger_dates(&last_day, from, 3),
+            vec![date(2027, 1, 31), date(2027, 2, 28), date(2027, 3, 31)]
+        );
+
+        let three_before_end = reminder(json!({"remindType": "monthly", "monthlyMode": "days_before_end", "daysBeforeEnd": 3}));
+        assert_eq!(
+            trigger_dates(&three_before_end, from, 3),
+            vec![date(2027, 1, 28), date(2027, 2, 25), date(2027, 3, 28)]
+        );
+
+        // 设置 31 日时，小月在最后一天触发
+        let thirty_first = reminder(json!({"remindType": "monthly", "monthdays": [31]}));
+        assert_eq!(
+            trigger_dates(&thirty_first, from, 3),
+            vec![date(2027, 1, 31), date(2027, 2, 28), date(2027, 3, 31)]
+        );
+    }
+}
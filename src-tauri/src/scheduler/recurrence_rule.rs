@@ -0,0 +1,609 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, DateTime, Local, NaiveDate, TimeZone, Weekday};
+use cron::Schedule;
+
+use crate::scheduler::occurrence::get_last_day_of_month;
+use crate::scheduler::zone::ReminderZone;
+
+/// COUNT 计数时最多向前枚举的天数
+const MAX_COUNT_SCAN_DAYS: i64 = 366 * 20;
+
+/// 高级重复规则：cron 表达式或 iCalendar RRULE
+#[derive(Debug, Clone)]
+pub enum RecurrenceRule {
+    /// cron 表达式（自带触发时间）
+    Cron(Schedule),
+    /// RRULE（只决定日期，时间取提醒的 remind_time）
+    RRule(RRule),
+}
+
+impl RecurrenceRule {
+    /// 解析规则文本：包含 `FREQ=` 的视为 RRULE，否则视为 cron 表达式
+    pub fn parse(rule: &str) -> Result<Self, String> {
+        let rule = rule.trim();
+        if rule.is_empty() {
+            return Err("重复规则不能为空".to_string());
+        }
+
+        if rule.to_ascii_uppercase().contains("FREQ=") {
+            RRule::parse(rule).map(Self::RRule)
+        } else {
+            parse_cron(rule).map(Self::Cron)
+        }
+    }
+
+    /// 规则是否自带触发时间（cron 表达式自带时间，RRULE 使用 remind_time）
+    pub fn has_time(&self) -> bool {
+        matches!(self, Self::Cron(_))
+    }
+
+    /// 设置 RRULE 的默认起始日期（规则未指定 DTSTART 时使用），并计算 COUNT 的截止日期
+    pub fn with_start(self, start: NaiveDate) -> Self {
+        match self {
+            Self::RRule(rrule) => Self::RRule(rrule.with_start(start)),
+            cron => cron,
+        }
+    }
+
+    /// 判断指定日期（提醒时区中的日期）是否有触发，cron 表达式按提醒的时区解释
+    pub fn matches_date(&self, dtstart: NaiveDate, date: NaiveDate, zone: &ReminderZone) -> bool {
+        match self {
+            Self::Cron(_) => {
+                let Some(day_start) = zone.start_of(date) else {
+                    return false;
+                };
+                // cron 的 after 不包含起点，从前一秒开始查找
+                let after = day_start - chrono::Duration::seconds(1);
+                let next = match zone.tz() {
+                    Some(tz) => self.cron_next(after.with_timezone(&tz)).map(|dt| dt.with_timezone(&Local)),
+                    None => self.cron_next(after),
+                };
+                next.map_or(false, |next| zone.date(next) == date)
+            }
+            Self::RRule(rrule) => rrule.matches(dtstart, date),
+        }
+    }
+
+    /// cron 表达式在 `after` 之后（不含）的下一次触发时间
+    pub fn cron_next<Z: TimeZone>(&self, after: DateTime<Z>) -> Option<DateTime<Z>> {
+        match self {
+            Self::Cron(schedule) => schedule.after(&after).next(),
+            Self::RRule(_) => None,
+        }
+    }
+
+    /// cron 表达式在 (since, until] 区间内的最后一次触发时间
+    pub fn cron_last_between<Z: TimeZone>(&self, since: DateTime<Z>, until: DateTime<Z>) -> Option<DateTime<Z>> {
+        match self {
+            Self::Cron(schedule) => schedule.after(&since)
+                .take(10_000)
+                .take_while(|dt| *dt <= until)
+                .last(),
+            Self::RRule(_) => None,
+        }
+    }
+}
+
+/// 解析 cron 表达式，支持标准 5 段（分 时 日 月 周）以及带秒的 6/7 段格式
+fn parse_cron(expr: &str) -> Result<Schedule, String> {
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+
+    let normalized = match fields.len() {
+        // 标准 cron：补上秒，并把 0-7 的数字星期转换为英文缩写（cron 库中 1 表示周日）
+        5 => format!(
+            "0 {} {} {} {} {}",
+            fields[0], fields[1], fields[2], fields[3],
+            normalize_cron_weekdays(fields[4])
+        ),
+        6 | 7 => fields.join(" "),
+        _ => return Err(format!("cron 表达式格式无效: {}", expr)),
+    };
+
+    Schedule::from_str(&normalized).map_err(|e| format!("cron 表达式无效: {} ({})", expr, e))
+}
+
+/// 将标准 cron 星期字段中的数字（0 和 7 为周日）转换为英文缩写，步长数字保持不变
+fn normalize_cron_weekdays(field: &str) -> String {
+    const NAMES: [&str; 8] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+
+    let mut result = String::new();
+    let mut digits = String::new();
+    let mut after_slash = false;
+
+    let flush = |digits: &mut String, after_slash: bool, result: &mut String| {
+        if digits.is_empty() {
+            return;
+        }
+        match digits.parse::<usize>() {
+            Ok(n) if !after_slash && n < NAMES.len() => result.push_str(NAMES[n]),
+            _ => result.push_str(digits),
+        }
+        digits.clear();
+    };
+
+    for c in field.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else {
+            flush(&mut digits, after_slash, &mut result);
+            after_slash = c == '/';
+            result.push(c);
+        }
+    }
+    flush(&mut digits, after_slash, &mut result);
+
+    result
+}
+
+/// RRULE 频率
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// iCalendar RRULE（RFC 5545）的按日期子集
+///
+/// 支持 FREQ（DAILY/WEEKLY/MONTHLY/YEARLY）、INTERVAL、BYDAY（含序号，如 -1FR）、
+/// BYMONTHDAY（含负数）、BYMONTH、BYSETPOS、COUNT、UNTIL 以及可选的 DTSTART 行。
+/// 时间部分由提醒的 remind_time 决定，因此不支持 BYHOUR/BYMINUTE/BYSECOND。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+    /// 规则中的 DTSTART，未指定时由调用方提供起始日期
+    pub dtstart: Option<NaiveDate>,
+    /// 第 COUNT 次发生的日期，在确定 DTSTART 时计算（在枚举范围内未达到 COUNT 时为空）
+    pub count_cutoff: Option<NaiveDate>,
+}
+
+impl RRule {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut dtstart = None;
+        let mut rule_line = None;
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let upper = line.to_ascii_uppercase();
+            if upper.starts_with("DTSTART") {
+                // DTSTART:20260101T090000Z 或 DTSTART;TZID=Asia/Shanghai:20260101T090000
+                let value = upper.rsplit(':').next().unwrap_or_default();
+                dtstart = Some(parse_ical_date(value)?);
+            } else {
+                rule_line = Some(upper.trim_start_matches("RRULE:").to_string());
+            }
+        }
+
+        let rule_line = rule_line.ok_or("缺少 RRULE")?;
+
+        let mut freq = None;
+        let mut rrule = RRule {
+            freq: Frequency::Daily,
+            interval: 1,
+            by_day: vec![],
+            by_month_day: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            count: None,
+            until: None,
+            dtstart,
+            count_cutoff: None,
+        };
+
+        for part in rule_line.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=')
+                .ok_or_else(|| format!("RRULE 参数格式无效: {}", part))?;
+
+            match key {
+                "FREQ" => {
+                    freq = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(format!("不支持的 FREQ: {}", value)),
+                    });
+                }
+                "INTERVAL" => {
+                    rrule.interval = value.parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| format!("INTERVAL 无效: {}", value))?;
+                }
+                "BYDAY" => {
+                    rrule.by_day = value.split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    rrule.by_month_day = parse_int_list(value, -31, 31, "BYMONTHDAY")?;
+                }
+                "BYMONTH" => {
+                    rrule.by_month = parse_int_list(value, 1, 12, "BYMONTH")?
+                        .into_iter()
+                        .map(|m| m as u32)
+                        .collect();
+                }
+                "BYSETPOS" => {
+                    rrule.by_set_pos = parse_int_list(value, -366, 366, "BYSETPOS")?;
+                }
+                "COUNT" => {
+                    rrule.count = Some(value.parse().map_err(|_| format!("COUNT 无效: {}", value))?);
+                }
+                "UNTIL" => {
+                    rrule.until = Some(parse_ical_date(value)?);
+                }
+                // 周起始日固定为周一
+                "WKST" => {}
+                _ => return Err(format!("不支持的 RRULE 参数: {}", key)),
+            }
+        }
+
+        rrule.freq = freq.ok_or("RRULE 缺少 FREQ")?;
+
+        if rrule.count.is_some() && rrule.until.is_some() {
+            return Err("COUNT 和 UNTIL 不能同时使用".to_string());
+        }
+
+        Ok(match rrule.dtstart {
+            Some(start) => rrule.with_start(start),
+            None => rrule,
+        })
+    }
+
+    /// 设置默认起始日期（规则已指定 DTSTART 时保留规则中的），并计算 COUNT 的截止日期
+    pub fn with_start(mut self, start: NaiveDate) -> Self {
+        let start = *self.dtstart.get_or_insert(start);
+        self.count_cutoff = match self.count {
+            Some(count) if count > 0 => self.nth_occurrence(start, count as usize),
+            _ => None,
+        };
+        self
+    }
+
+    /// 判断指定日期是否为规则的一次发生（`default_start` 在规则未指定 DTSTART 时使用）
+    pub fn matches(&self, default_start: NaiveDate, date: NaiveDate) -> bool {
+        let start = self.dtstart.unwrap_or(default_start);
+
+        if date < start || self.until.map_or(false, |until| date > until) {
+            return false;
+        }
+
+        if !self.matches_without_count(start, date) {
+            return false;
+        }
+
+        match self.count {
+            Some(0) => false,
+            // 在枚举范围内未达到 COUNT 时不限制；未确定 DTSTART 时按本次的起始日期现算
+            Some(count) => {
+                let cutoff = match self.dtstart {
+                    Some(_) => self.count_cutoff,
+                    None => self.nth_occurrence(start, count as usize),
+                };
+                cutoff.map_or(true, |last| date <= last)
+            }
+            None => true,
+        }
+    }
+
+    fn matches_without_count(&self, start: NaiveDate, date: NaiveDate) -> bool {
+        if !self.in_interval(start, date) || !self.matches_filters(start, date) {
+            return false;
+        }
+
+        if self.by_set_pos.is_empty() {
+            return true;
+        }
+
+        self.period_occurrences(start, &self.period_dates(date)).contains(&date)
+    }
+
+    /// 周期内满足规则的日期（按 BYSETPOS 筛选后排序）
+    fn period_occurrences(&self, start: NaiveDate, period: &[NaiveDate]) -> Vec<NaiveDate> {
+        let candidates: Vec<NaiveDate> = period.iter()
+            .copied()
+            .filter(|d| self.matches_filters(start, *d))
+            .collect();
+        if self.by_set_pos.is_empty() {
+            return candidates;
+        }
+
+        let len = candidates.len() as i32;
+        let mut selected: Vec<NaiveDate> = self.by_set_pos.iter()
+            .filter_map(|&pos| {
+                let index = if pos > 0 { pos - 1 } else { len + pos };
+                (index >= 0 && index < len).then(|| candidates[index as usize])
+            })
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    /// 从起始日期起第 `n` 次发生的日期，按周期逐个枚举，最多枚举 `MAX_COUNT_SCAN_DAYS` 天
+    fn nth_occurrence(&self, start: NaiveDate, n: usize) -> Option<NaiveDate> {
+        let end = start + chrono::Duration::days(MAX_COUNT_SCAN_DAYS);
+        let mut remaining = n;
+        let mut cursor = start;
+
+        while cursor <= end {
+            let period = self.period_dates(cursor);
+            let last = *period.last()?;
+            if self.in_interval(start, cursor) {
+                for date in self.period_occurrences(start, &period) {
+                    if date < start || date > end {
+                        continue;
+                    }
+                    remaining -= 1;
+                    if remaining == 0 {
+                        return Some(date);
+                    }
+                }
+            }
+            cursor = last.succ_opt()?;
+        }
+
+        None
+    }
+
+    /// 判断日期所在周期是否满足 INTERVAL
+    fn in_interval(&self, start: NaiveDate, date: NaiveDate) -> bool {
+        let interval = self.interval as i64;
+
+        let periods = match self.freq {
+            Frequency::Daily => (date - start).num_days(),
+            Frequency::Weekly => (week_start(date) - week_start(start)).num_days() / 7,
+            Frequency::Monthly => {
+                (date.year() as i64 * 12 + date.month() as i64)
+                    - (start.year() as i64 * 12 + start.month() as i64)
+            }
+            Frequency::Yearly => (date.year() - start.year()) as i64,
+        };
+
+        periods % interval == 0
+    }
+
+    /// 判断日期是否满足 BYMONTH / BYMONTHDAY / BYDAY（未设置时按 DTSTART 推断）
+    fn matches_filters(&self, start: NaiveDate, date: NaiveDate) -> bool {
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        let last_day = get_last_day_of_month(date.year(), date.month()) as i32;
+        let day = date.day() as i32;
+
+        if !self.by_month_day.is_empty() {
+            let matched = self.by_month_day.iter().any(|&md| {
+                if md > 0 { day == md } else { day == last_day + md + 1 }
+            });
+            if !matched {
+                return false;
+            }
+        }
+
+        if !self.by_day.is_empty() {
+            return self.by_day.iter().any(|&(ordinal, weekday)| {
+                date.weekday() == weekday && ordinal.map_or(true, |n| self.matches_ordinal(date, n))
+            });
+        }
+
+        // 未指定具体日期时，按 DTSTART 推断
+        match self.freq {
+            Frequency::Daily => true,
+            Frequency::Weekly => date.weekday() == start.weekday(),
+            Frequency::Monthly => !self.by_month_day.is_empty() || day == start.day() as i32,
+            Frequency::Yearly => {
+                if !self.by_month_day.is_empty() {
+                    true
+                } else if !self.by_month.is_empty() {
+                    day == start.day() as i32
+                } else {
+                    date.month() == start.month() && date.day() == start.day()
+                }
+            }
+        }
+    }
+
+    /// 判断日期是否为当月（或当年）的第 n 个该星期几，n 为负数时从末尾倒数
+    fn matches_ordinal(&self, date: NaiveDate, n: i32) -> bool {
+        let within_month = self.freq == Frequency::Monthly
+            || (self.freq == Frequency::Yearly && !self.by_month.is_empty());
+
+        let (index_from_start, index_from_end) = if within_month {
+            let last_day = get_last_day_of_month(date.year(), date.month()) as i32;
+            let day = date.day() as i32;
+            ((day - 1) / 7 + 1, (last_day - day) / 7 + 1)
+        } else if self.freq == Frequency::Yearly {
+            let days_in_year = if NaiveDate::from_ymd_opt(date.year(), 2, 29).is_some() { 366 } else { 365 };
+            let ordinal0 = date.ordinal0() as i32;
+            (ordinal0 / 7 + 1, (days_in_year - 1 - ordinal0) / 7 + 1)
+        } else {
+            // 按天、按周重复时序号无意义
+            return true;
+        };
+
+        if n > 0 { index_from_start == n } else { index_from_end == -n }
+    }
+
+    /// 日期所在周期内的所有日期（用于 BYSETPOS）
+    fn period_dates(&self, date: NaiveDate) -> Vec<NaiveDate> {
+        let (first, last) = match self.freq {
+            Frequency::Daily => (date, date),
+            Frequency::Weekly => {
+                let first = week_start(date);
+                (first, first + chrono::Duration::days(6))
+            }
+            Frequency::Monthly => {
+                let last_day = get_last_day_of_month(date.year(), date.month()) as u32;
+                (date.with_day(1).unwrap_or(date), date.with_day(last_day).unwrap_or(date))
+            }
+            Frequency::Yearly => (
+                NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date),
+                NaiveDate::from_ymd_opt(date.year(), 12, 31).unwrap_or(date),
+            ),
+        };
+
+        first.iter_days().take_while(|d| *d <= last).collect()
+    }
+}
+
+/// 所在周的周一
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// 解析 iCalendar 日期（YYYYMMDD，忽略时间部分）
+fn parse_ical_date(value: &str) -> Result<NaiveDate, String> {
+    let date_part = value.get(..8).unwrap_or(value);
+    NaiveDate::parse_from_str(date_part, "%Y%m%d").map_err(|_| format!("日期格式无效: {}", value))
+}
+
+/// 解析 BYDAY 项，如 MO、2TU、-1FR
+fn parse_by_day(item: &str) -> Result<(Option<i32>, Weekday), String> {
+    let item = item.trim();
+    if item.len() < 2 {
+        return Err(format!("BYDAY 无效: {}", item));
+    }
+
+    let (ordinal, day) = item.split_at(item.len() - 2);
+    let weekday = match day {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(format!("BYDAY 无效: {}", item)),
+    };
+
+    let ordinal = if ordinal.is_empty() {
+        None
+    } else {
+        let n: i32 = ordinal.trim_start_matches('+')
+            .parse()
+            .map_err(|_| format!("BYDAY 无效: {}", item))?;
+        if n == 0 || !(-53..=53).contains(&n) {
+            return Err(format!("BYDAY 无效: {}", item));
+        }
+        Some(n)
+    };
+
+    Ok((ordinal, weekday))
+}
+
+/// 解析逗号分隔的整数列表并检查范围（不允许 0）
+fn parse_int_list(value: &str, min: i32, max: i32, name: &str) -> Result<Vec<i32>, String> {
+    value.split(',')
+        .map(|item| {
+            item.trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|n| *n != 0 && (min..=max).contains(n))
+                .ok_or_else(|| format!("{} 无效: {}", name, item))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// 从 `from` 起（最多两年内）规则的前 `n` 次发生日期
+    fn occurrences(rule: &str, start: NaiveDate, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        let rule = RecurrenceRule::parse(rule).unwrap().with_start(start);
+        let zone = ReminderZone::new(None, Default::default());
+        from.iter_days()
+            .take(800)
+            .filter(|d| rule.matches_date(start, *d, &zone))
+            .take(n)
+            .collect()
+    }
+
+    #[test]
+    fn last_friday_of_month() {
+        assert_eq!(
+            occurrences("FREQ=MONTHLY;BYDAY=-1FR", date(2026, 1, 1), date(2026, 1, 1), 3),
+            vec![date(2026, 1, 30), date(2026, 2, 27), date(2026, 3, 27)]
+        );
+    }
+
+    #[test]
+    fn every_other_tuesday() {
+        assert_eq!(
+            occurrences("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU", date(2026, 10, 19), date(2026, 10, 1), 3),
+            vec![date(2026, 10, 20), date(2026, 11, 3), date(2026, 11, 17)]
+        );
+    }
+
+    #[test]
+    fn first_workday_of_quarter() {
+        let expected = vec![date(2026, 1, 1), date(2026, 4, 1), date(2026, 7, 1), date(2026, 10, 1), date(2027, 1, 1)];
+        assert_eq!(
+            occurrences("FREQ=MONTHLY;BYMONTH=1,4,7,10;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1", date(2026, 1, 1), date(2026, 1, 1), 5),
+            expected
+        );
+        assert_eq!(
+            occurrences("FREQ=MONTHLY;INTERVAL=3;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1", date(2026, 1, 1), date(2026, 1, 1), 5),
+            expected
+        );
+    }
+
+    #[test]
+    fn count_stops_after_nth_occurrence() {
+        assert_eq!(
+            occurrences("FREQ=DAILY;COUNT=3", date(2026, 1, 5), date(2026, 1, 1), 10),
+            vec![date(2026, 1, 5), date(2026, 1, 6), date(2026, 1, 7)]
+        );
+        assert_eq!(
+            occurrences("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1,-1;COUNT=3", date(2026, 1, 15), date(2026, 1, 1), 10),
+            vec![date(2026, 1, 30), date(2026, 2, 2), date(2026, 2, 27)]
+        );
+        assert!(occurrences("FREQ=DAILY;COUNT=0", date(2026, 1, 1), date(2026, 1, 1), 10).is_empty());
+    }
+
+    #[test]
+    fn count_cutoff_is_computed_once_start_is_known() {
+        let rule = RRule::parse("FREQ=WEEKLY;BYDAY=MO,FR;COUNT=3").unwrap();
+        assert_eq!(rule.count_cutoff, None);
+        assert_eq!(rule.with_start(date(2026, 10, 16)).count_cutoff, Some(date(2026, 10, 23)));
+
+        let rule = RRule::parse("DTSTART:20261016T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO,FR;COUNT=3").unwrap();
+        assert_eq!(rule.count_cutoff, Some(date(2026, 10, 23)));
+        // 规则中的 DTSTART 优先于提醒的起始日期
+        assert_eq!(rule.with_start(date(2020, 1, 1)).count_cutoff, Some(date(2026, 10, 23)));
+    }
+
+    #[test]
+    fn until_is_inclusive() {
+        assert_eq!(
+            occurrences("DTSTART:20260101T090000Z\nRRULE:FREQ=WEEKLY;UNTIL=20260115", date(2020, 1, 1), date(2025, 12, 1), 10),
+            vec![date(2026, 1, 1), date(2026, 1, 8), date(2026, 1, 15)]
+        );
+        assert!(RRule::parse("FREQ=DAILY;COUNT=3;UNTIL=20260115").is_err());
+    }
+
+    #[test]
+    fn cron_uses_reminder_zone() {
+        // 纽约 2026-03-08 02:00 跳到 03:00，当天没有 02:30
+        let rule = RecurrenceRule::parse("30 2 * * *").unwrap();
+        let new_york = ReminderZone::new(Some(chrono_tz::America::New_York), Default::default());
+        let shanghai = ReminderZone::new(Some(chrono_tz::Asia::Shanghai), Default::default());
+        assert!(!rule.matches_date(date(2026, 1, 1), date(2026, 3, 8), &new_york));
+        assert!(rule.matches_date(date(2026, 1, 1), date(2026, 3, 9), &new_york));
+        assert!(rule.matches_date(date(2026, 1, 1), date(2026, 3, 8), &shanghai));
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Utc,
+};
+use chrono_tz::Tz;
+
+use crate::models::reminder::{DstPolicy, Reminder};
+
+/// 提醒的时区：未设置（或无效）时使用本机时区
+///
+/// 调度器内部统一使用本机时间表示触发时刻，日期匹配和时间点换算在提醒自己的时区中进行。
+#[derive(Debug, Clone)]
+pub struct ReminderZone {
+    tz: Option<Tz>,
+    dst_policy: DstPolicy,
+}
+
+impl ReminderZone {
+    pub fn new(tz: Option<Tz>, dst_policy: DstPolicy) -> Self {
+        Self { tz, dst_policy }
+    }
+
+    pub fn of(reminder: &Reminder) -> Self {
+        Self::new(
+            reminder.time_zone.as_deref().and_then(parse_time_zone),
+            reminder.dst_policy.clone().unwrap_or_default(),
+        )
+    }
+
+    /// 设置的时区（为空表示本机时区）
+    pub fn tz(&self) -> Option<Tz> {
+        self.tz
+    }
+
+    /// 时区名称（本机时区无法识别时返回 "Local"）
+    pub fn name(&self) -> String {
+        match self.tz {
+            Some(tz) => tz.name().to_string(),
+            None => local_time_zone_name(),
+        }
+    }
+
+    /// 触发时刻在该时区的日期时间
+    pub fn naive(&self, instant: DateTime<Local>) -> NaiveDateTime {
+        match self.tz {
+            Some(tz) => instant.with_timezone(&tz).naive_local(),
+            None => instant.naive_local(),
+        }
+    }
+
+    /// 触发时刻在该时区的日期
+    pub fn date(&self, instant: DateTime<Local>) -> NaiveDate {
+        self.naive(instant).date()
+    }
+
+    /// 触发时刻在该时区的表示（带偏移量）
+    pub fn fixed(&self, instant: DateTime<Local>) -> DateTime<FixedOffset> {
+        match self.tz {
+            Some(tz) => instant.with_timezone(&tz).fixed_offset(),
+            None => instant.fixed_offset(),
+        }
+    }
+
+    /// 该时区某天某时对应的触发时刻，夏令时跳变按 `dst_policy` 处理
+    pub fn at(&self, date: NaiveDate, time: NaiveTime) -> Option<DateTime<Local>> {
+        self.resolve(date.and_time(time))
+    }
+
+    /// 该时区某天开始（00:00 或跳变后的第一个时刻）
+    pub fn start_of(&self, date: NaiveDate) -> Option<DateTime<Local>> {
+        let midnight = date.and_hms_opt(0, 0, 0)?;
+        let resolved = match self.tz {
+            Some(tz) => resolve_in(&tz, midnight, &DstPolicy::Shift),
+            None => resolve_in(&Local, midnight, &DstPolicy::Shift),
+        };
+        resolved.map(|dt| dt.with_timezone(&Local))
+    }
+
+    /// 将该时区的日期时间换算为触发时刻
+    pub fn resolve(&self, naive: NaiveDateTime) -> Option<DateTime<Local>> {
+        let resolved = match self.tz {
+            Some(tz) => resolve_in(&tz, naive, &self.dst_policy),
+            None => resolve_in(&Local, naive, &self.dst_policy),
+        };
+        resolved.map(|dt| dt.with_timezone(&Local))
+    }
+}
+
+/// 解析 IANA 时区名称
+pub fn parse_time_zone(name: &str) -> Option<Tz> {
+    name.trim().parse::<Tz>().ok()
+}
+
+/// 本机时区的 IANA 名称
+pub fn local_time_zone_name() -> String {
+    iana_time_zone::get_timezone().unwrap_or_else(|_| "Local".to_string())
+}
+
+/// 在指定时区中解析日期时间：
+/// - 重复的时间（夏令时结束）取较早的一次
+/// - 不存在的时间（夏令时开始）按策略跳过，或按跳变前的偏移量顺延
+fn resolve_in<Z: TimeZone>(tz: &Z, naive: NaiveDateTime, policy: &DstPolicy) -> Option<DateTime<Utc>> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Some(dt.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest.with_timezone(&Utc)),
+        LocalResult::None => match policy {
+            DstPolicy::Skip => None,
+            DstPolicy::Shift => {
+                let offset = tz.offset_from_local_datetime(&(naive - Duration::hours(3)))
+                    .earliest()?
+                    .fix();
+                let utc = naive - Duration::seconds(offset.local_minus_utc() as i64);
+                Some(Utc.from_utc_datetime(&utc))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_york(policy: DstPolicy) -> ReminderZone {
+        ReminderZone::new(Some(chrono_tz::America::New_York), policy)
+    }
+
+    fn utc(zone: &ReminderZone, date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+        let time = NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        zone.at(date, time).map(|dt| dt.with_timezone(&Utc))
+    }
+
+    fn utc_at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn spring_forward_gap_follows_policy() {
+        // 纽约 2026-03-08 02:00 跳到 03:00
+        let gap_day = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+
+        // 顺延：按跳变前的偏移量（UTC-5）换算，即夏令时 03:30
+        let shift = new_york(DstPolicy::Shift);
+        assert_eq!(utc(&shift, gap_day, "02:30"), Some(utc_at(2026, 3, 8, 7, 30)));
+        let shifted = utc(&shift, gap_day, "02:30").unwrap().with_timezone(&Local);
+        assert_eq!(shift.naive(shifted), gap_day.and_hms_opt(3, 30, 0).unwrap());
+
+        assert_eq!(utc(&new_york(DstPolicy::Skip), gap_day, "02:30"), None);
+
+        // 跳变前后的时间不受策略影响
+        for policy in [DstPolicy::Shift, DstPolicy::Skip] {
+            let zone = new_york(policy);
+            assert_eq!(utc(&zone, gap_day, "01:30"), Some(utc_at(2026, 3, 8, 6, 30)));
+            assert_eq!(utc(&zone, gap_day, "03:30"), Some(utc_at(2026, 3, 8, 7, 30)));
+        }
+    }
+
+    #[test]
+    fn fall_back_overlap_uses_earlier_instant() {
+        // 纽约 2026-11-01 02:00 回拨到 01:00，01:30 出现两次
+        let overlap_day = NaiveDate::from_ymd_opt(2026, 11, 1).unwrap();
+        for policy in [DstPolicy::Shift, DstPolicy::Skip] {
+            let zone = new_york(policy);
+            assert_eq!(utc(&zone, overlap_day, "01:30"), Some(utc_at(2026, 11, 1, 5, 30)));
+            assert_eq!(utc(&zone, overlap_day, "02:30"), Some(utc_at(2026, 11, 1, 7, 30)));
+        }
+    }
+
+    #[test]
+    fn dates_and_day_start_are_in_reminder_zone() {
+        let shanghai = ReminderZone::new(Some(chrono_tz::Asia::Shanghai), DstPolicy::Shift);
+        // UTC 2026-10-18 20:00 为上海 10 月 19 日 04:00
+        let instant = utc_at(2026, 10, 18, 20, 0).with_timezone(&Local);
+        assert_eq!(shanghai.date(instant), NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+        assert_eq!(shanghai.fixed(instant).offset().local_minus_utc(), 8 * 3600);
+        assert_eq!(
+            shanghai.start_of(NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()).map(|dt| dt.with_timezone(&Utc)),
+            Some(utc_at(2026, 10, 18, 16, 0))
+        );
+        assert_eq!(shanghai.name(), "Asia/Shanghai");
+        assert!(parse_time_zone("Mars/Olympus").is_none());
+    }
+}
@@ -0,0 +1,84 @@
+use tauri::{AppHandle, Emitter};
+use tokio::time::Duration;
+use chrono::Utc;
+use crate::error::AppResult;
+use crate::models::backup::{BackupInfo, BackupKind};
+use crate::notification::tray;
+use crate::storage::{backup, settings_repo};
+use crate::scheduler::manager;
+
+/// 检查是否需要自动备份的间隔
+const AUTO_BACKUP_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// 列出所有备份（最新的在前）
+pub fn list_backups(app: &AppHandle) -> AppResult<Vec<BackupInfo>> {
+    let settings = settings_repo::get_all()?;
+    backup::list_backups(app, &settings)
+}
+
+/// 立即手动备份
+pub fn create_backup(app: &AppHandle) -> AppResult<BackupInfo> {
+    let settings = settings_repo::get_all()?;
+    let info = backup::create_backup(app, &settings, BackupKind::Manual)?;
+    println!("[备份] 已手动备份: {}", info.file_name);
+    Ok(info)
+}
+
+/// 从备份恢复：停止调度器，备份当前数据后替换数据库，再重新启动调度器
+pub async fn restore_backup(app: &AppHandle, file_name: &str) -> AppResult<()> {
+    let settings = settings_repo::get_all()?;
+    let path = backup::resolve_backup(app, &settings, file_name)?;
+    backup::verify_backup(&path)?;
+
+    manager::stop_scheduler().await;
+
+    let result = backup::create_backup(app, &settings, BackupKind::PreRestore)
+        .and_then(|current| {
+            println!("[备份] 恢复前已备份当前数据: {}", current.file_name);
+            backup::restore_from(app, &path)
+        });
+
+    // 无论恢复是否成功都重新启动调度器
+    manager::spawn_scheduler(app.clone());
+    result?;
+
+    // 设置也随数据库一起恢复
+    if let Ok(settings) = settings_repo::get_all() {
+        tray::set_dnd_checked(app, settings.dnd_enabled);
+    }
+    let _ = app.emit("database-restored", file_name.to_string());
+    println!("[备份] 已从备份恢复: {}", file_name);
+    Ok(())
+}
+
+/// 到了备份间隔时自动备份，并清理超出保留数量的旧备份
+fn backup_if_due(app: &AppHandle) -> AppResult<()> {
+    let settings = settings_repo::get_all()?;
+    if !settings.backup_enabled {
+        return Ok(());
+    }
+
+    let interval = chrono::Duration::hours(settings.backup_interval_hours.max(1) as i64);
+    let last_backup_at = backup::list_backups(app, &settings)?
+        .into_iter()
+        .find(|b| b.kind == BackupKind::Auto)
+        .map(|b| b.created_at);
+    if last_backup_at.map_or(false, |at| Utc::now() - at < interval) {
+        return Ok(());
+    }
+
+    let info = backup::create_backup(app, &settings, BackupKind::Auto)?;
+    let removed = backup::prune_auto_backups(app, &settings)?;
+    println!("[备份] 已自动备份: {}（清理旧备份 {} 个）", info.file_name, removed);
+    Ok(())
+}
+
+/// 定时自动备份（应用启动后在后台运行）
+pub async fn run_auto_backup(app: AppHandle) {
+    loop {
+        if let Err(e) = backup_if_due(&app) {
+            eprintln!("[备份] 自动备份失败: {}", e);
+        }
+        tokio::time::sleep(AUTO_BACKUP_CHECK_INTERVAL).await;
+    }
+}
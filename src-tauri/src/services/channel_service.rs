@@ -0,0 +1,187 @@
+use tauri::AppHandle;
+use crate::error::{AppError, AppResult};
+use crate::models::channel::{Channel, ChannelKind, CreateChannelRequest, UpdateChannelRequest};
+use crate::models::settings::AppSettings;
+use crate::notification::channel::{self, NotificationMessage};
+use crate::storage::channel_repo;
+
+fn channel_not_found(uuid: &str) -> AppError {
+    AppError::NotFound { entity: "通知渠道", id: uuid.to_string() }
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("渠道名称不能为空".to_string());
+    }
+    Ok(())
+}
+
+/// 检查提醒引用的渠道是否存在
+pub fn validate_channel_refs(channel_uuids: &[&Option<Vec<String>>]) -> AppResult<()> {
+    for uuid in channel_uuids.iter().filter_map(|c| c.as_ref()).flatten() {
+        if channel_repo::get_by_uuid(uuid)?.is_none() {
+            return Err(channel_not_found(uuid));
+        }
+    }
+    Ok(())
+}
+
+pub fn get_channels(_app: &AppHandle) -> AppResult<Vec<Channel>> {
+    channel_repo::get_all()
+}
+
+pub fn create_channel(_app: &AppHandle, request: CreateChannelRequest) -> AppResult<Channel> {
+    validate_name(&request.name).map_err(AppError::Validation)?;
+
+    channel::build(request.kind, &request.config)?;
+    channel_repo::create(request)
+}
+
+pub fn update_channel(_app: &AppHandle, request: UpdateChannelRequest) -> AppResult<Channel> {
+    if let Some(ref name) = request.name {
+        validate_name(name).map_err(AppError::Validation)?;
+    }
+
+    let existing = channel_repo::get_by_uuid(&request.uuid)?.ok_or_else(|| channel_not_found(&request.uuid))?;
+    if let Some(ref config) = request.config {
+        channel::build(existing.kind, config)?;
+    }
+
+    channel_repo::update(request)
+}
+
+pub fn delete_channel(_app: &AppHandle, uuid: &str) -> AppResult<()> {
+    channel_repo::delete(uuid)
+}
+
+/// 设置中填写的 Server酱 SendKey（为空时返回 None）
+fn legacy_sendkey(settings: &AppSettings) -> Option<&str> {
+    settings.serverchan_sendkey.as_deref().filter(|k| !k.trim().is_empty())
+}
+
+/// 兼容旧版前端：设置中的 Server酱 对应的渠道（按 SendKey 匹配）
+pub fn legacy_serverchan_channel(settings: &AppSettings) -> AppResult<Option<Channel>> {
+    let Some(sendkey) = legacy_sendkey(settings) else {
+        return Ok(None);
+    };
+    Ok(channel_repo::get_all()?
+        .into_iter()
+        .find(|channel| channel.kind == ChannelKind::ServerChan && channel.config["sendkey"] == sendkey))
+}
+
+/// 设置中的 Server酱 对应的渠道，不存在时按设置创建（未填写 SendKey 时返回 None）
+pub fn ensure_legacy_serverchan(settings: &AppSettings) -> AppResult<Option<Channel>> {
+    if let Some(channel) = legacy_serverchan_channel(settings)? {
+        return Ok(Some(channel));
+    }
+    let Some(sendkey) = legacy_sendkey(settings) else {
+        return Ok(None);
+    };
+
+    let channel = channel_repo::create(CreateChannelRequest {
+        name: "Server酱".to_string(),
+        kind: ChannelKind::ServerChan,
+        config: serde_json::json!({ "domain": settings.serverchan_domain, "sendkey": sendkey }),
+        is_enabled: Some(true),
+        allow_during_dnd: Some(settings.dnd_allow_serverchan),
+    })?;
+    println!("[通知渠道] 已按设置创建 Server酱 渠道: {}", channel.uuid);
+    Ok(Some(channel))
+}
+
+/// 旧版设置页修改 Server酱 配置后，同步到对应的渠道（按原 SendKey 匹配，首次填写时创建渠道）
+pub fn sync_legacy_serverchan(old: &AppSettings, new: &AppSettings) -> AppResult<()> {
+    let Some(new_sendkey) = legacy_sendkey(new) else {
+        return Ok(());
+    };
+
+    let Some(channel) = legacy_serverchan_channel(old)? else {
+        ensure_legacy_serverchan(new)?;
+        return Ok(());
+    };
+
+    let config_changed = legacy_sendkey(old) != Some(new_sendkey) || old.serverchan_domain != new.serverchan_domain;
+    let dnd_changed = old.dnd_allow_serverchan != new.dnd_allow_serverchan;
+    if !config_changed && !dnd_changed {
+        return Ok(());
+    }
+
+    channel_repo::update(UpdateChannelRequest {
+        uuid: channel.uuid,
+        name: None,
+        config: config_changed
+            .then(|| serde_json::json!({ "domain": new.serverchan_domain, "sendkey": new_sendkey })),
+        is_enabled: None,
+        allow_during_dnd: dnd_changed.then_some(new.dnd_allow_serverchan),
+    })?;
+    Ok(())
+}
+
+/// 向渠道发送一条测试消息（停用的渠道也会发送）
+pub async fn test_channel(_app: &AppHandle, uuid: &str) -> AppResult<()> {
+    let channel = channel_repo::get_by_uuid(uuid)?.ok_or_else(|| channel_not_found(uuid))?;
+    channel::build(channel.kind, &channel.config)?.send(&NotificationMessage::test(&channel.name)).await
+}
+
+/// 用尚未保存的配置发送一条测试消息
+pub async fn test_config(_app: &AppHandle, kind: ChannelKind, config: &serde_json::Value) -> AppResult<bool> {
+    channel::build(kind, config)?.send(&NotificationMessage::test(kind.display_name())).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::database::init_test_database;
+
+    fn settings(sendkey: Option<&str>, domain: Option<&str>, allow_during_dnd: bool) -> AppSettings {
+        AppSettings {
+            serverchan_sendkey: sendkey.map(str::to_string),
+            serverchan_domain: domain.map(str::to_string),
+            dnd_allow_serverchan: allow_during_dnd,
+            ..Default::default()
+        }
+    }
+
+    fn channels_with_sendkey(sendkey: &str) -> Vec<Channel> {
+        channel_repo::get_all()
+            .unwrap()
+            .into_iter()
+            .filter(|c| c.config["sendkey"] == sendkey)
+            .collect()
+    }
+
+    #[test]
+    fn sync_creates_then_updates_legacy_channel() {
+        init_test_database();
+        let empty = settings(None, None, true);
+        let first = settings(Some("SCT-sync-first"), None, true);
+        sync_legacy_serverchan(&empty, &first).unwrap();
+
+        let created = channels_with_sendkey("SCT-sync-first");
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].kind, ChannelKind::ServerChan);
+        assert!(created[0].is_enabled && created[0].allow_during_dnd);
+
+        // 再次保存相同设置不重复创建
+        sync_legacy_serverchan(&first, &first).unwrap();
+        assert_eq!(channels_with_sendkey("SCT-sync-first").len(), 1);
+
+        let changed = settings(Some("SCT-sync-second"), Some("sctapi.example.com"), false);
+        sync_legacy_serverchan(&first, &changed).unwrap();
+        assert!(channels_with_sendkey("SCT-sync-first").is_empty());
+        let updated = channels_with_sendkey("SCT-sync-second");
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].uuid, created[0].uuid);
+        assert_eq!(updated[0].config["domain"], "sctapi.example.com");
+        assert!(!updated[0].allow_during_dnd);
+    }
+
+    #[test]
+    fn sync_ignores_empty_sendkey() {
+        init_test_database();
+        sync_legacy_serverchan(&settings(None, None, true), &settings(Some("  "), None, true)).unwrap();
+        assert!(channels_with_sendkey("  ").is_empty());
+        assert_eq!(legacy_serverchan_channel(&settings(Some("  "), None, true)).unwrap().map(|c| c.uuid), None);
+    }
+}
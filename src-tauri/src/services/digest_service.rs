@@ -0,0 +1,283 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+use tauri::AppHandle;
+use crate::error::{AppError, AppResult};
+use crate::models::digest::Digest;
+use crate::models::settings::{AppSettings, DigestFrequency};
+use crate::notification::{email, escape_html};
+use crate::storage::{reminder_log_repo, settings_repo};
+use crate::utils::time::parse_time;
+
+/// 检查是否需要发送摘要的间隔
+const DIGEST_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// 记录上次定时摘要对应时间点的设置项
+const LAST_SENT_KEY: &str = "digest_last_sent_at";
+
+/// 摘要覆盖的天数
+fn period_days(frequency: DigestFrequency) -> i64 {
+    match frequency {
+        DigestFrequency::Daily => 1,
+        DigestFrequency::Weekly => 7,
+    }
+}
+
+/// 某天的发送时间点（本地时间）
+fn schedule_at(settings: &AppSettings, date: NaiveDate) -> Option<DateTime<Local>> {
+    let time = parse_time(&settings.digest_time)?;
+    Local.from_local_datetime(&date.and_time(time)).earliest()
+}
+
+/// 不晚于 `now` 的最近一次发送时间点，及其摘要开始时间
+fn latest_schedule(settings: &AppSettings, now: DateTime<Local>) -> Option<(DateTime<Local>, DateTime<Local>)> {
+    let days = period_days(settings.digest_frequency);
+    let mut date = now.date_naive();
+    if settings.digest_frequency == DigestFrequency::Weekly {
+        let weekday = settings.digest_weekday.clamp(1, 7) as i64;
+        date -= Duration::days((date.weekday().number_from_monday() as i64 - weekday).rem_euclid(7));
+    }
+    if schedule_at(settings, date)? > now {
+        date -= Duration::days(days);
+    }
+
+    let until = schedule_at(settings, date)?;
+    let since = schedule_at(settings, date - Duration::days(days))?;
+    Some((since, until))
+}
+
+/// 统计一段时间内的提醒记录
+pub fn build_digest(since: DateTime<Utc>, until: DateTime<Utc>) -> AppResult<Digest> {
+    Ok(Digest { since, until, entries: reminder_log_repo::summarize(since, until)? })
+}
+
+/// 邮件标题
+fn subject(digest: &Digest, frequency: DigestFrequency) -> String {
+    let since = digest.since.with_timezone(&Local);
+    let until = digest.until.with_timezone(&Local);
+    match frequency {
+        DigestFrequency::Daily => format!("叮咚 每日摘要 {}", since.format("%Y-%m-%d")),
+        DigestFrequency::Weekly => format!("叮咚 每周摘要 {} ~ {}", since.format("%m-%d"), until.format("%m-%d")),
+    }
+}
+
+/// 完成率（完成次数 / 触发次数）
+fn completion_rate(completed: i64, triggered: i64) -> String {
+    if triggered == 0 {
+        return "-".to_string();
+    }
+    format!("{:.0}%", completed as f64 * 100.0 / triggered as f64)
+}
+
+/// 摘要的纯文本正文
+fn render_text(digest: &Digest) -> String {
+    let mut text = format!(
+        "{} ~ {}\n\n",
+        digest.since.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+        digest.until.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+    );
+    if digest.entries.is_empty() {
+        text.push_str("这段时间没有提醒记录。");
+        return text;
+    }
+    for entry in &digest.entries {
+        text.push_str(&format!(
+            "{}：触发 {}，完成 {}，忽略 {}，超时 {}，错过 {}\n",
+            entry.title, entry.triggered, entry.completed, entry.dismissed, entry.timed_out, entry.missed
+        ));
+    }
+    text
+}
+
+/// 摘要的 HTML 正文
+fn render_html(digest: &Digest) -> String {
+    let mut html = format!(
+        "<p>{} ~ {}</p>",
+        digest.since.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+        digest.until.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+    );
+    if digest.entries.is_empty() {
+        html.push_str("<p>这段时间没有提醒记录。</p>");
+        return html;
+    }
+
+    let cell = "style=\"padding:4px 12px;border-bottom:1px solid #eee;text-align:right\"";
+    html.push_str("<table style=\"border-collapse:collapse\"><tr>");
+    for header in ["提醒", "触发", "完成", "忽略", "超时", "错过", "完成率"] {
+        html.push_str(&format!("<th {}>{}</th>", cell, header));
+    }
+    html.push_str("</tr>");
+
+    let mut total = [0i64; 5];
+    for entry in &digest.entries {
+        let counts = [entry.triggered, entry.completed, entry.dismissed, entry.timed_out, entry.missed];
+        html.push_str(&format!("<tr><td {}>{}</td>", cell, escape_html(&entry.title)));
+        for (i, count) in counts.iter().enumerate() {
+            total[i] += count;
+            html.push_str(&format!("<td {}>{}</td>", cell, count));
+        }
+        html.push_str(&format!("<td {}>{}</td></tr>", cell, completion_rate(entry.completed, entry.triggered)));
+    }
+
+    html.push_str(&format!("<tr><th {}>合计</th>", cell));
+    for count in total {
+        html.push_str(&format!("<th {}>{}</th>", cell, count));
+    }
+    html.push_str(&format!("<th {}>{}</th></tr></table>", cell, completion_rate(total[1], total[0])));
+    html
+}
+
+/// 把摘要发给设置中的收件人
+async fn deliver(settings: &AppSettings, digest: &Digest) -> AppResult<()> {
+    let smtp = settings.smtp.as_ref()
+        .ok_or_else(|| AppError::Validation("尚未配置 SMTP 发件服务器".to_string()))?;
+    if settings.digest_recipients.is_empty() {
+        return Err(AppError::Validation("摘要收件人不能为空".to_string()));
+    }
+    let to = settings.digest_recipients.iter().map(|addr| email::parse_mailbox(addr)).collect::<AppResult<Vec<_>>>()?;
+
+    let subject = subject(digest, settings.digest_frequency);
+    email::send_mail(smtp, &to, &subject, render_text(digest), render_html(digest)).await
+}
+
+/// 立即发送截至现在的一份摘要（不影响定时摘要）
+pub async fn send_digest(_app: &AppHandle) -> AppResult<Digest> {
+    let settings = settings_repo::get_all()?;
+    let until = Utc::now();
+    let since = until - Duration::days(period_days(settings.digest_frequency));
+    let digest = build_digest(since, until)?;
+    deliver(&settings, &digest).await?;
+    Ok(digest)
+}
+
+/// 到了发送时间点且该时间点尚未发送过时发送摘要
+async fn send_digest_if_due() -> AppResult<()> {
+    let settings = settings_repo::get_all()?;
+    if !settings.digest_enabled {
+        return Ok(());
+    }
+    let Some((since, until)) = latest_schedule(&settings, Local::now()) else {
+        return Ok(());
+    };
+
+    let last_sent_at = settings_repo::get(LAST_SENT_KEY)?
+        .and_then(|value| serde_json::from_str::<DateTime<Utc>>(&value).ok());
+    if last_sent_at.map_or(false, |at| at >= until) {
+        return Ok(());
+    }
+
+    let digest = build_digest(since.with_timezone(&Utc), until.with_timezone(&Utc))?;
+    deliver(&settings, &digest).await?;
+    settings_repo::set(LAST_SENT_KEY, &serde_json::to_string(&until.with_timezone(&Utc))?)?;
+    println!("[摘要] 已发送摘要邮件（{} 个提醒）", digest.entries.len());
+    Ok(())
+}
+
+/// 定时发送摘要邮件（应用启动后在后台运行）
+pub async fn run_digest(_app: AppHandle) {
+    loop {
+        if let Err(e) = send_digest_if_due().await {
+            eprintln!("[摘要] 发送摘要邮件失败: {}", e);
+        }
+        tokio::time::sleep(DIGEST_CHECK_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::reminder::CreateReminderRequest;
+    use crate::models::reminder_log::LogAction;
+    use crate::models::settings::{SmtpSecurity, SmtpSettings};
+    use crate::notification::test_server::SmtpSink;
+    use crate::storage::database::init_test_database;
+    use crate::storage::reminder_repo;
+
+    fn create_reminder(title: &str) -> String {
+        let request: CreateReminderRequest = serde_json::from_value(serde_json::json!({
+            "title": title,
+            "remindTime": "09:00",
+            "remindType": "daily",
+        }))
+        .unwrap();
+        reminder_repo::create(request).unwrap().uuid
+    }
+
+    fn log(reminder_uuid: &str, action: LogAction, note: Option<&str>) {
+        reminder_log_repo::create(reminder_uuid, action, Utc::now(), None, note.map(str::to_string)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn sends_digest_built_from_logs() {
+        init_test_database();
+        let standup = create_reminder("站会 <每日>");
+        let water = create_reminder("喝水");
+        log(&standup, LogAction::Triggered, None);
+        log(&standup, LogAction::Triggered, None);
+        log(&standup, LogAction::Triggered, Some("重复提醒"));
+        log(&standup, LogAction::Completed, None);
+        log(&standup, LogAction::TimedOut, None);
+        log(&water, LogAction::Triggered, None);
+        log(&water, LogAction::Missed, None);
+        log(&water, LogAction::Skipped, None);
+
+        let now = Utc::now();
+        let mut digest = build_digest(now - Duration::minutes(1), now + Duration::minutes(1)).unwrap();
+        digest.entries.retain(|e| e.reminder_uuid == standup || e.reminder_uuid == water);
+        // 条目按排序值和 uuid 排列，两条提醒的先后不固定
+        let mut counts: Vec<_> = digest.entries.iter()
+            .map(|e| (e.title.as_str(), e.triggered, e.completed, e.dismissed, e.timed_out, e.missed))
+            .collect();
+        counts.sort();
+        let mut expected = vec![("站会 <每日>", 2, 1, 0, 1, 0), ("喝水", 1, 0, 0, 0, 1)];
+        expected.sort();
+        assert_eq!(counts, expected);
+
+        let sink = SmtpSink::start().await;
+        let settings = AppSettings {
+            smtp: Some(SmtpSettings {
+                host: "127.0.0.1".to_string(),
+                port: Some(sink.port),
+                security: SmtpSecurity::None,
+                username: String::new(),
+                password: String::new(),
+                from: "叮咚 <dingdong@example.com>".to_string(),
+            }),
+            digest_recipients: vec!["me@example.com".to_string(), "Team <team@example.com>".to_string()],
+            ..AppSettings::default()
+        };
+        deliver(&settings, &digest).await.unwrap();
+
+        let mails = sink.mails();
+        assert_eq!(mails.len(), 1);
+        let mail = &mails[0];
+        assert!(mail.from.contains("<dingdong@example.com>"), "{}", mail.from);
+        assert_eq!(mail.to, vec!["<me@example.com>", "<team@example.com>"]);
+        assert!(mail.data.contains("multipart/alternative"));
+
+        let text = mail.part("text/plain").unwrap();
+        assert!(text.contains("站会 <每日>：触发 2，完成 1，忽略 0，超时 1，错过 0\n"), "{}", text);
+        assert!(text.contains("喝水：触发 1，完成 0，忽略 0，超时 0，错过 1\n"), "{}", text);
+
+        let html = mail.part("text/html").unwrap();
+        assert!(html.contains("站会 &lt;每日&gt;</td>"), "{}", html);
+        assert!(html.contains(">50%</td>"), "{}", html);
+        assert!(html.contains(">合计</th>"), "{}", html);
+    }
+
+    #[tokio::test]
+    async fn deliver_requires_smtp_and_recipients() {
+        let digest = Digest { since: Utc::now(), until: Utc::now(), entries: vec![] };
+        let mut settings = AppSettings { digest_recipients: vec!["me@example.com".to_string()], ..AppSettings::default() };
+        assert!(matches!(deliver(&settings, &digest).await, Err(AppError::Validation(_))));
+
+        settings.smtp = Some(SmtpSettings {
+            host: "127.0.0.1".to_string(),
+            port: Some(1),
+            security: SmtpSecurity::None,
+            username: String::new(),
+            password: String::new(),
+            from: "dingdong@example.com".to_string(),
+        });
+        settings.digest_recipients.clear();
+        assert!(matches!(deliver(&settings, &digest).await, Err(AppError::Validation(_))));
+    }
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use tauri::AppHandle;
+use crate::error::{AppError, AppResult};
+use crate::models::holiday::{HolidayCalendar, HolidayDay, HolidaySource};
+use crate::storage::holiday_repo;
+use crate::scheduler::{holiday_calendar, manager};
+
+/// 获取生效的节假日日历，可按年份过滤
+pub fn get_holidays(_app: &AppHandle, year: Option<i32>) -> AppResult<Vec<HolidayDay>> {
+    holiday_repo::get_effective(year)
+}
+
+/// 从 JSON 文件导入节假日日历（替换之前导入的数据），返回导入的天数
+pub fn import_calendar(_app: &AppHandle, path: &str) -> AppResult<usize> {
+    let content = fs::read_to_string(path)?;
+    let calendar: HolidayCalendar = serde_json::from_str(&content)
+        .map_err(|e| AppError::Validation(format!("节假日日历格式无效: {}", e)))?;
+    if calendar.days.is_empty() {
+        return Err(AppError::Validation("节假日日历为空".to_string()));
+    }
+
+    holiday_repo::replace_source(HolidaySource::User, &calendar.days)?;
+    holiday_calendar::reload()?;
+    manager::notify_reminders_changed();
+
+    println!("[节假日] 已导入日历 {}（{} 天）", calendar.version, calendar.days.len());
+    Ok(calendar.days.len())
+}
+
+/// 清除导入的数据，恢复为内置日历
+pub fn reset_calendar(_app: &AppHandle) -> AppResult<()> {
+    holiday_repo::clear_source(HolidaySource::User)?;
+    holiday_calendar::reload()?;
+    manager::notify_reminders_changed();
+    Ok(())
+}
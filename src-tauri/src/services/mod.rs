@@ -1,2 +1,6 @@
 pub mod reminder_service;
 pub mod settings_service;
+pub mod holiday_service;
+pub mod backup_service;
+pub mod channel_service;
+pub mod digest_service;
@@ -1,57 +1,464 @@
 use tauri::AppHandle;
-use crate::models::reminder::{Reminder, CreateReminderRequest, UpdateReminderRequest, ReminderLink};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use crate::models::reminder::{
+    Reminder, CreateReminderRequest, UpdateReminderRequest, ReminderLink, RemindType, MonthlyMode,
+    ReminderOccurrence,
+};
+use crate::error::{AppError, AppResult};
 use crate::models::reminder_log::ReminderLog;
-use crate::storage::{reminder_repo, reminder_log_repo};
+use crate::storage::{reminder_repo, reminder_log_repo, settings_repo};
 use crate::scheduler::manager;
 use crate::notification::popup;
+use crate::services::channel_service;
+use crate::scheduler::occurrence::get_last_day_of_month;
+use crate::scheduler::recurrence_rule::RecurrenceRule;
+use crate::scheduler::zone::parse_time_zone;
+use crate::utils::time::parse_time;
 
-pub fn get_all_reminders(_app: &AppHandle) -> Result<Vec<Reminder>, Box<dyn std::error::Error>> {
-    Ok(reminder_repo::get_all()?)
+/// 验证高级重复规则：必须是有效的 cron 表达式或 RRULE
+fn validate_recurrence_rule(recurrence_rule: Option<&str>) -> Result<(), String> {
+    let rule = recurrence_rule.ok_or("高级重复规则不能为空")?;
+    RecurrenceRule::parse(rule)?;
+    Ok(())
 }
 
-pub fn get_reminder_by_uuid(_app: &AppHandle, uuid: &str) -> Result<Option<Reminder>, Box<dyn std::error::Error>> {
-    Ok(reminder_repo::get_by_uuid(uuid)?)
+/// 验证间隔重复：间隔必须大于 0，生效时段需同时设置且格式有效
+fn validate_interval(
+    interval_value: Option<i32>,
+    window_start: Option<&str>,
+    window_end: Option<&str>,
+) -> Result<(), String> {
+    match interval_value {
+        Some(value) if value > 0 => {}
+        _ => return Err("间隔必须大于 0".to_string()),
+    }
+    match (window_start, window_end) {
+        (None, None) => Ok(()),
+        (Some(start), Some(end)) => {
+            parse_time(start).ok_or_else(|| format!("生效时段格式无效: {}", start))?;
+            parse_time(end).ok_or_else(|| format!("生效时段格式无效: {}", end))?;
+            Ok(())
+        }
+        _ => Err("生效时段的开始和结束时间需同时设置".to_string()),
+    }
 }
 
-pub fn create_reminder(_app: &AppHandle, request: CreateReminderRequest) -> Result<Reminder, Box<dyn std::error::Error>> {
+/// 验证每月重复方式所需的参数
+fn validate_monthly_mode(
+    monthly_mode: Option<&MonthlyMode>,
+    month_week: Option<u8>,
+    month_weekday: Option<u8>,
+    days_before_end: Option<u8>,
+) -> Result<(), String> {
+    let valid_weekday = month_weekday.map_or(false, |w| (1..=7).contains(&w));
+    match monthly_mode {
+        None | Some(MonthlyMode::Days) | Some(MonthlyMode::LastDay) => Ok(()),
+        Some(MonthlyMode::NthWeekday) => {
+            if !month_week.map_or(false, |w| (1..=5).contains(&w)) {
+                return Err("第几个星期必须在 1-5 之间".to_string());
+            }
+            if !valid_weekday {
+                return Err("星期必须在 1-7 之间".to_string());
+            }
+            Ok(())
+        }
+        Some(MonthlyMode::LastWeekday) => {
+            if !valid_weekday {
+                return Err("星期必须在 1-7 之间".to_string());
+            }
+            Ok(())
+        }
+        Some(MonthlyMode::DaysBeforeEnd) => {
+            match days_before_end {
+                Some(days) if days <= 27 => Ok(()),
+                _ => Err("月末前天数必须在 0-27 之间".to_string()),
+            }
+        }
+    }
+}
+
+/// 验证每年重复的月、日（按闰年计算当月最大天数，即允许 2 月 29 日）
+fn validate_yearly(yearly_month: Option<u8>, yearly_day: Option<u8>) -> Result<(), String> {
+    let (Some(month), Some(day)) = (yearly_month, yearly_day) else {
+        return Err("每年提醒需要设置月和日".to_string());
+    };
+    if !(1..=12).contains(&month) {
+        return Err("月份必须在 1-12 之间".to_string());
+    }
+    let max_day = get_last_day_of_month(2000, month as u32);
+    if day == 0 || day > max_day {
+        return Err(format!("{}月没有{}日", month, day));
+    }
+    Ok(())
+}
+
+/// 验证农历重复：农历日必须在 1-30 之间，农历月在 1-12 之间，提前天数不能为负
+fn validate_lunar(lunar_month: Option<u8>, lunar_day: Option<u8>, days_before: Option<i32>) -> Result<(), String> {
+    match lunar_day {
+        Some(day) if (1..=30).contains(&day) => {}
+        _ => return Err("农历日必须在 1-30 之间".to_string()),
+    }
+    if lunar_month.map_or(false, |month| !(1..=12).contains(&month)) {
+        return Err("农历月必须在 1-12 之间".to_string());
+    }
+    if days_before.map_or(false, |days| !(0..=30).contains(&days)) {
+        return Err("提前天数必须在 0-30 之间".to_string());
+    }
+    Ok(())
+}
+
+/// 验证并整理多个触发时间点：格式必须有效，按时间排序去重
+fn normalize_remind_times(remind_times: &mut Option<Vec<String>>) -> Result<(), String> {
+    let Some(times) = remind_times else {
+        return Ok(());
+    };
+
+    let mut parsed = vec![];
+    for time in times.iter() {
+        let parsed_time = parse_time(time).ok_or_else(|| format!("提醒时间格式无效: {}", time))?;
+        parsed.push((parsed_time, time.clone()));
+    }
+    parsed.sort();
+    parsed.dedup_by(|a, b| a.0 == b.0);
+
+    *times = parsed.into_iter().map(|(_, time)| time).collect();
+    Ok(())
+}
+
+/// 验证生效日期范围和最大触发次数
+fn validate_limits(
+    starts_on: Option<NaiveDate>,
+    ends_on: Option<NaiveDate>,
+    max_occurrences: Option<i32>,
+) -> Result<(), String> {
+    if let (Some(start), Some(end)) = (starts_on, ends_on) {
+        if end < start {
+            return Err("结束日期不能早于开始日期".to_string());
+        }
+    }
+    if max_occurrences.map_or(false, |n| n < 0) {
+        return Err("最大触发次数不能为负数".to_string());
+    }
+    Ok(())
+}
+
+/// 验证时区：必须是有效的 IANA 时区名称（空字符串表示使用本机时区）
+fn validate_time_zone(time_zone: Option<&str>) -> Result<(), String> {
+    match time_zone {
+        Some(name) if !name.is_empty() && parse_time_zone(name).is_none() => {
+            Err(format!("无效的时区: {}", name))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 验证单次提醒的指定日期：每个时间点的日期和时间（按提醒时区）都不能早于当前时间
+fn validate_remind_date(remind_date: Option<NaiveDate>, slots: &[&str], time_zone: Option<&str>) -> Result<(), String> {
+    let Some(date) = remind_date else {
+        return Ok(());
+    };
+
+    for slot in slots {
+        let time = parse_time(slot).ok_or_else(|| format!("提醒时间格式无效: {}", slot))?;
+        let is_past = match time_zone.and_then(parse_time_zone) {
+            Some(tz) => date.and_time(time)
+                .and_local_timezone(tz)
+                .earliest()
+                .map_or(true, |dt| dt <= Utc::now()),
+            None => date.and_time(time)
+                .and_local_timezone(Local)
+                .earliest()
+                .map_or(true, |dt| dt <= Local::now()),
+        };
+        if is_past {
+            return Err(format!("提醒日期不能早于当前时间: {} {}", date, slot));
+        }
+    }
+
+    Ok(())
+}
+
+/// 提醒的触发时间点（设置了多个时间点时使用 remind_times，否则为 remind_time）
+fn time_slots<'a>(remind_time: &'a str, remind_times: Option<&'a Vec<String>>) -> Vec<&'a str> {
+    match remind_times {
+        Some(times) if !times.is_empty() => times.iter().map(|t| t.as_str()).collect(),
+        _ => vec![remind_time],
+    }
+}
+
+/// 一个事件的 (旧版开关, 请求中的渠道列表, 当前的渠道列表)
+type LegacyNotifyEvent<'a> = (Option<bool>, &'a mut Option<Vec<String>>, &'a [String]);
+
+/// 兼容旧版前端：把 notifyOn* 开关转换为设置中 Server酱 渠道在对应事件渠道列表中的增删
+///
+/// 请求中已指定渠道列表的事件以列表为准。
+fn apply_legacy_notify_flags(events: [LegacyNotifyEvent; 3]) -> AppResult<()> {
+    if events.iter().all(|(flag, channels, _)| flag.is_none() || channels.is_some()) {
+        return Ok(());
+    }
+
+    let settings = settings_repo::get_all()?;
+    let enabling = events.iter().any(|(flag, channels, _)| *flag == Some(true) && channels.is_none());
+    let channel = if enabling {
+        channel_service::ensure_legacy_serverchan(&settings)?
+            .ok_or_else(|| AppError::Validation("请先在设置中填写 Server酱 SendKey".to_string()))?
+    } else {
+        match channel_service::legacy_serverchan_channel(&settings)? {
+            Some(channel) => channel,
+            None => return Ok(()),
+        }
+    };
+
+    for (flag, channels, current) in events {
+        let (Some(enabled), None) = (flag, channels.as_ref()) else {
+            continue;
+        };
+        let mut list: Vec<String> = current.iter().filter(|uuid| **uuid != channel.uuid).cloned().collect();
+        if enabled {
+            list.push(channel.uuid.clone());
+        }
+        *channels = Some(list);
+    }
+    Ok(())
+}
+
+/// 兼容旧版前端：按渠道列表填充 notifyOn* 开关
+fn with_legacy_flags(mut reminders: Vec<Reminder>) -> AppResult<Vec<Reminder>> {
+    let Some(channel) = channel_service::legacy_serverchan_channel(&settings_repo::get_all()?)? else {
+        return Ok(reminders);
+    };
+    for reminder in &mut reminders {
+        reminder.notify_on_trigger = reminder.trigger_channels.contains(&channel.uuid);
+        reminder.notify_on_complete = reminder.complete_channels.contains(&channel.uuid);
+        reminder.notify_on_timeout = reminder.timeout_channels.contains(&channel.uuid);
+    }
+    Ok(reminders)
+}
+
+fn with_legacy_flag(reminder: Reminder) -> AppResult<Reminder> {
+    Ok(with_legacy_flags(vec![reminder])?.remove(0))
+}
+
+pub fn get_all_reminders(_app: &AppHandle) -> AppResult<Vec<Reminder>> {
+    with_legacy_flags(reminder_repo::get_all()?)
+}
+
+pub fn get_reminder_by_uuid(_app: &AppHandle, uuid: &str) -> AppResult<Option<Reminder>> {
+    reminder_repo::get_by_uuid(uuid)?.map(with_legacy_flag).transpose()
+}
+
+pub fn create_reminder(_app: &AppHandle, mut request: CreateReminderRequest) -> AppResult<Reminder> {
     // 验证链接数量
-    ReminderLink::validate_links(&request.links)?;
-    Ok(reminder_repo::create(request)?)
+    ReminderLink::validate_links(&request.links).map_err(AppError::Validation)?;
+    normalize_remind_times(&mut request.remind_times).map_err(AppError::Validation)?;
+    // 多个时间点时 remind_time 取最早的一个（用于排序和兼容旧版本）
+    if let Some(first) = request.remind_times.as_ref().and_then(|t| t.first()) {
+        request.remind_time = first.clone();
+    }
+    validate_time_zone(request.time_zone.as_deref()).map_err(AppError::Validation)?;
+    apply_legacy_notify_flags([
+        (request.notify_on_trigger, &mut request.trigger_channels, &[]),
+        (request.notify_on_complete, &mut request.complete_channels, &[]),
+        (request.notify_on_timeout, &mut request.timeout_channels, &[]),
+    ])?;
+    channel_service::validate_channel_refs(&[&request.trigger_channels, &request.complete_channels, &request.timeout_channels])?;
+    validate_limits(request.starts_on, request.ends_on, request.max_occurrences).map_err(AppError::Validation)?;
+    if request.remind_type == Some(RemindType::Once) {
+        validate_remind_date(
+            request.remind_date,
+            &time_slots(&request.remind_time, request.remind_times.as_ref()),
+            request.time_zone.as_deref(),
+        ).map_err(AppError::Validation)?;
+    }
+    if request.remind_type == Some(RemindType::Advanced) {
+        validate_recurrence_rule(request.recurrence_rule.as_deref()).map_err(AppError::Validation)?;
+    }
+    if request.remind_type == Some(RemindType::Interval) {
+        validate_interval(
+            request.interval_value,
+            request.window_start.as_deref(),
+            request.window_end.as_deref(),
+        ).map_err(AppError::Validation)?;
+    }
+    if request.remind_type == Some(RemindType::Monthly) {
+        validate_monthly_mode(
+            request.monthly_mode.as_ref(),
+            request.month_week,
+            request.month_weekday,
+            request.days_before_end,
+        ).map_err(AppError::Validation)?;
+    }
+    if request.remind_type == Some(RemindType::Yearly) {
+        validate_yearly(request.yearly_month, request.yearly_day).map_err(AppError::Validation)?;
+    }
+    if request.remind_type == Some(RemindType::Lunar) {
+        validate_lunar(request.lunar_month, request.lunar_day, request.lunar_days_before).map_err(AppError::Validation)?;
+    }
+    let reminder = reminder_repo::create(request)?;
+    manager::notify_reminders_changed();
+    with_legacy_flag(reminder)
 }
 
-pub fn update_reminder(_app: &AppHandle, request: UpdateReminderRequest) -> Result<Reminder, Box<dyn std::error::Error>> {
+pub fn update_reminder(_app: &AppHandle, mut request: UpdateReminderRequest) -> AppResult<Reminder> {
     // 验证链接数量
-    ReminderLink::validate_links(&request.links)?;
-    Ok(reminder_repo::update(request)?)
+    ReminderLink::validate_links(&request.links).map_err(AppError::Validation)?;
+    normalize_remind_times(&mut request.remind_times).map_err(AppError::Validation)?;
+    // 多个时间点时 remind_time 取最早的一个（用于排序和兼容旧版本）
+    if let Some(first) = request.remind_times.as_ref().and_then(|t| t.first()) {
+        request.remind_time = Some(first.clone());
+    }
+    validate_time_zone(request.time_zone.as_deref()).map_err(AppError::Validation)?;
+    if request.notify_on_trigger.is_some() || request.notify_on_complete.is_some() || request.notify_on_timeout.is_some() {
+        let existing = reminder_repo::get_by_uuid(&request.uuid)?.ok_or_else(|| AppError::reminder_not_found(&request.uuid))?;
+        apply_legacy_notify_flags([
+            (request.notify_on_trigger, &mut request.trigger_channels, &existing.trigger_channels),
+            (request.notify_on_complete, &mut request.complete_channels, &existing.complete_channels),
+            (request.notify_on_timeout, &mut request.timeout_channels, &existing.timeout_channels),
+        ])?;
+    }
+    channel_service::validate_channel_refs(&[&request.trigger_channels, &request.complete_channels, &request.timeout_channels])?;
+    if request.starts_on.is_some() || request.ends_on.is_some() || request.max_occurrences.is_some() {
+        let existing = reminder_repo::get_by_uuid(&request.uuid)?.ok_or_else(|| AppError::reminder_not_found(&request.uuid))?;
+        validate_limits(
+            request.starts_on.unwrap_or(existing.starts_on),
+            request.ends_on.unwrap_or(existing.ends_on),
+            request.max_occurrences,
+        ).map_err(AppError::Validation)?;
+    }
+    if request.remind_date.is_some()
+        || request.remind_type.is_some()
+        || request.remind_time.is_some()
+        || request.remind_times.is_some()
+        || request.time_zone.is_some()
+        || request.recurrence_rule.is_some()
+        || request.interval_value.is_some()
+        || request.window_start.is_some()
+        || request.window_end.is_some()
+        || request.monthly_mode.is_some()
+        || request.month_week.is_some()
+        || request.month_weekday.is_some()
+        || request.days_before_end.is_some()
+        || request.yearly_month.is_some()
+        || request.yearly_day.is_some()
+        || request.lunar_month.is_some()
+        || request.lunar_day.is_some()
+        || request.lunar_days_before.is_some()
+    {
+        let existing = reminder_repo::get_by_uuid(&request.uuid)?.ok_or_else(|| AppError::reminder_not_found(&request.uuid))?;
+        let remind_type = request.remind_type.as_ref().unwrap_or(&existing.remind_type);
+        if *remind_type == RemindType::Once {
+            // 未修改日期时按已保存的日期校验（如从其他类型切换为单次提醒、修改了时间或时区）
+            let remind_time = request.remind_time.as_deref().unwrap_or(&existing.remind_time);
+            let remind_times = request.remind_times.as_ref().or(existing.remind_times.as_ref());
+            let time_zone = request.time_zone.as_deref().or(existing.time_zone.as_deref());
+            validate_remind_date(
+                request.remind_date.or(existing.remind_date),
+                &time_slots(remind_time, remind_times),
+                time_zone,
+            ).map_err(AppError::Validation)?;
+        }
+        if *remind_type == RemindType::Advanced {
+            let rule = request.recurrence_rule.as_deref().or(existing.recurrence_rule.as_deref());
+            validate_recurrence_rule(rule).map_err(AppError::Validation)?;
+        }
+        if *remind_type == RemindType::Interval {
+            validate_interval(
+                request.interval_value.or(existing.interval_value),
+                request.window_start.as_deref().or(existing.window_start.as_deref()),
+                request.window_end.as_deref().or(existing.window_end.as_deref()),
+            ).map_err(AppError::Validation)?;
+        }
+        if *remind_type == RemindType::Monthly {
+            validate_monthly_mode(
+                request.monthly_mode.as_ref().or(existing.monthly_mode.as_ref()),
+                request.month_week.or(existing.month_week),
+                request.month_weekday.or(existing.month_weekday),
+                request.days_before_end.or(existing.days_before_end),
+            ).map_err(AppError::Validation)?;
+        }
+        if *remind_type == RemindType::Yearly {
+            validate_yearly(
+                request.yearly_month.or(existing.yearly_month),
+                request.yearly_day.or(existing.yearly_day),
+            ).map_err(AppError::Validation)?;
+        }
+        if *remind_type == RemindType::Lunar {
+            validate_lunar(
+                request.lunar_month.or(existing.lunar_month),
+                request.lunar_day.or(existing.lunar_day),
+                request.lunar_days_before.or(existing.lunar_days_before),
+            ).map_err(AppError::Validation)?;
+        }
+    }
+    let reminder = reminder_repo::update(request)?;
+    manager::notify_reminders_changed();
+    with_legacy_flag(reminder)
+}
+
+/// 获取提醒接下来的触发时间（同时给出提醒时区和本机时区）
+pub fn get_reminder_occurrences(
+    _app: &AppHandle,
+    uuid: &str,
+    count: usize,
+) -> AppResult<Vec<ReminderOccurrence>> {
+    let reminder = reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?;
+    Ok(manager::get_reminder_occurrences(&reminder, count))
+}
+
+pub fn delete_reminder(_app: &AppHandle, uuid: &str) -> AppResult<()> {
+    reminder_repo::soft_delete(uuid)?;
+    manager::notify_reminders_changed();
+    Ok(())
+}
+
+pub fn toggle_reminder(_app: &AppHandle, uuid: &str) -> AppResult<Reminder> {
+    let reminder = reminder_repo::toggle_enabled(uuid)?;
+    manager::notify_reminders_changed();
+    with_legacy_flag(reminder)
 }
 
-pub fn delete_reminder(_app: &AppHandle, uuid: &str) -> Result<(), Box<dyn std::error::Error>> {
-    Ok(reminder_repo::soft_delete(uuid)?)
+/// 跳过提醒的下一次触发，返回被跳过的触发时间（没有下一次时返回 None）
+pub fn skip_next_occurrence(_app: &AppHandle, uuid: &str) -> AppResult<Option<DateTime<Utc>>> {
+    let reminder = reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?;
+    manager::skip_next_occurrence(&reminder)
 }
 
-pub fn toggle_reminder(_app: &AppHandle, uuid: &str) -> Result<Reminder, Box<dyn std::error::Error>> {
-    Ok(reminder_repo::toggle_enabled(uuid)?)
+/// 暂停提醒到指定时间
+pub fn pause_reminder(_app: &AppHandle, uuid: &str, until: DateTime<Utc>) -> AppResult<Reminder> {
+    if until <= Utc::now() {
+        return Err(AppError::Validation("暂停截止时间必须晚于当前时间".to_string()));
+    }
+    let reminder = reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?;
+    manager::pause_reminder(&reminder, until)?;
+    with_legacy_flag(reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?)
 }
 
-pub fn reorder_reminders(_app: &AppHandle, uuids: &[String]) -> Result<(), Box<dyn std::error::Error>> {
-    Ok(reminder_repo::update_sort_orders(uuids)?)
+/// 恢复暂停的提醒
+pub fn resume_reminder(_app: &AppHandle, uuid: &str) -> AppResult<Reminder> {
+    let reminder = reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?;
+    manager::resume_reminder(&reminder)?;
+    with_legacy_flag(reminder_repo::get_by_uuid(uuid)?.ok_or_else(|| AppError::reminder_not_found(uuid))?)
 }
 
-pub async fn complete_reminder(app: &AppHandle, uuid: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+pub fn reorder_reminders(_app: &AppHandle, uuids: &[String]) -> AppResult<()> {
+    reminder_repo::update_sort_orders(uuids)
+}
+
+pub async fn complete_reminder(app: &AppHandle, uuid: &str) -> AppResult<()> {
     let result = manager::complete_reminder_action(app, uuid).await;
     // 关闭弹窗窗口
     let _ = popup::close_reminder_popup(app, uuid);
     result
 }
 
-pub async fn snooze_reminder(app: &AppHandle, uuid: &str, minutes: i32) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+pub async fn snooze_reminder(app: &AppHandle, uuid: &str, minutes: i32) -> AppResult<()> {
     let result = manager::snooze_reminder_action(app, uuid, minutes).await;
     // 关闭弹窗窗口
     let _ = popup::close_reminder_popup(app, uuid);
     result
 }
 
-pub async fn dismiss_reminder(app: &AppHandle, uuid: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+pub async fn dismiss_reminder(app: &AppHandle, uuid: &str) -> AppResult<()> {
     let result = manager::dismiss_reminder_action(app, uuid).await;
     // 关闭弹窗窗口
     let _ = popup::close_reminder_popup(app, uuid);
@@ -64,11 +471,63 @@ pub fn get_reminder_logs(
     start_date: Option<String>,
     end_date: Option<String>,
     limit: Option<i32>,
-) -> Result<Vec<ReminderLog>, Box<dyn std::error::Error>> {
-    Ok(reminder_log_repo::query(
+) -> AppResult<Vec<ReminderLog>> {
+    reminder_log_repo::query(
         reminder_uuid.as_deref(),
         start_date.as_deref(),
         end_date.as_deref(),
         limit,
-    )?)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::database::init_test_database;
+
+    #[test]
+    fn legacy_notify_flags_map_onto_serverchan_channel() {
+        init_test_database();
+        settings_repo::set("serverchan_sendkey", "\"SCT-legacy-flags\"").unwrap();
+
+        let mut request: CreateReminderRequest = serde_json::from_value(serde_json::json!({
+            "title": "旧版开关",
+            "remindTime": "09:00",
+            "remindType": "daily",
+            "notifyOnTrigger": true,
+            "notifyOnComplete": false,
+            "completeChannels": ["explicit"],
+        }))
+        .unwrap();
+        apply_legacy_notify_flags([
+            (request.notify_on_trigger, &mut request.trigger_channels, &[]),
+            (request.notify_on_complete, &mut request.complete_channels, &[]),
+            (request.notify_on_timeout, &mut request.timeout_channels, &[]),
+        ])
+        .unwrap();
+
+        let channel = channel_service::legacy_serverchan_channel(&settings_repo::get_all().unwrap())
+            .unwrap()
+            .expect("应按设置创建 Server酱 渠道");
+        assert_eq!(request.trigger_channels, Some(vec![channel.uuid.clone()]));
+        // 已指定渠道列表时以列表为准
+        assert_eq!(request.complete_channels, Some(vec!["explicit".to_string()]));
+        assert_eq!(request.timeout_channels, None);
+
+        request.complete_channels = None;
+        let reminder = with_legacy_flag(reminder_repo::create(request).unwrap()).unwrap();
+        assert!(reminder.notify_on_trigger);
+        assert!(!reminder.notify_on_complete && !reminder.notify_on_timeout);
+
+        // 关闭开关时只移除 Server酱 渠道
+        let current = vec![channel.uuid.clone(), "other".to_string()];
+        let mut trigger_channels = None;
+        apply_legacy_notify_flags([
+            (Some(false), &mut trigger_channels, &current),
+            (None, &mut None, &[]),
+            (None, &mut None, &[]),
+        ])
+        .unwrap();
+        assert_eq!(trigger_channels, Some(vec!["other".to_string()]));
+    }
 }
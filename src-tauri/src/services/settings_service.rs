@@ -1,25 +1,63 @@
 use tauri::AppHandle;
 use tauri_plugin_autostart::ManagerExt;
-use crate::models::reminder::Reminder;
-use crate::models::settings::AppSettings;
-use crate::storage::{settings_repo, reminder_repo};
-use chrono::Local;
+use crate::models::reminder::{Reminder, UpcomingReminder};
+use crate::error::{AppError, AppResult};
+use crate::models::settings::{AppSettings, SmtpSettings};
+use crate::storage::settings_repo;
+use crate::scheduler::manager;
+use crate::notification::{email, tray};
+use crate::services::channel_service;
+use crate::utils::time::parse_time;
 
-pub fn get_settings(_app: &AppHandle) -> Result<AppSettings, Box<dyn std::error::Error>> {
-    Ok(settings_repo::get_all()?)
+pub fn get_settings(_app: &AppHandle) -> AppResult<AppSettings> {
+    settings_repo::get_all()
 }
 
-pub fn update_settings(app: &AppHandle, settings: AppSettings) -> Result<(), Box<dyn std::error::Error>> {
+/// 校验摘要邮件设置
+fn validate_digest(settings: &AppSettings) -> AppResult<()> {
+    if parse_time(&settings.digest_time).is_none() {
+        return Err(AppError::Validation(format!("摘要发送时间无效: {}", settings.digest_time)));
+    }
+    if !(1..=7).contains(&settings.digest_weekday) {
+        return Err(AppError::Validation("摘要发送星期必须在 1-7 之间".to_string()));
+    }
+    for addr in &settings.digest_recipients {
+        email::parse_mailbox(addr)?;
+    }
+    if settings.digest_enabled && (settings.smtp.is_none() || settings.digest_recipients.is_empty()) {
+        return Err(AppError::Validation("开启摘要邮件前需先配置 SMTP 发件服务器和收件人".to_string()));
+    }
+    Ok(())
+}
+
+pub fn update_settings(app: &AppHandle, settings: AppSettings) -> AppResult<()> {
+    validate_digest(&settings)?;
+
     // 如果 auto_start 设置改变，同步更新系统自启动状态
     let old_settings = settings_repo::get_all()?;
     if old_settings.auto_start != settings.auto_start {
         set_auto_start_internal(app, settings.auto_start)?;
     }
 
-    Ok(settings_repo::update_all(&settings)?)
+    settings_repo::update_all(&settings)?;
+    channel_service::sync_legacy_serverchan(&old_settings, &settings)?;
+
+    // 勿扰开关或时段可能已变更
+    tray::set_dnd_checked(app, settings.dnd_enabled);
+    manager::notify_dnd_changed();
+    Ok(())
 }
 
-pub fn set_auto_start(app: &AppHandle, enabled: bool) -> Result<(), Box<dyn std::error::Error>> {
+/// 开关勿扰模式（设置页和托盘菜单共用）
+pub fn set_dnd(app: &AppHandle, enabled: bool) -> AppResult<()> {
+    settings_repo::set("dnd_enabled", &serde_json::to_string(&enabled)?)?;
+    tray::set_dnd_checked(app, enabled);
+    manager::notify_dnd_changed();
+    println!("[勿扰] {}", if enabled { "已开启" } else { "已关闭" });
+    Ok(())
+}
+
+pub fn set_auto_start(app: &AppHandle, enabled: bool) -> AppResult<()> {
     // 更新系统自启动
     set_auto_start_internal(app, enabled)?;
 
@@ -28,19 +66,19 @@ pub fn set_auto_start(app: &AppHandle, enabled: bool) -> Result<(), Box<dyn std:
     Ok(())
 }
 
-fn set_auto_start_internal(app: &AppHandle, enabled: bool) -> Result<(), Box<dyn std::error::Error>> {
+fn set_auto_start_internal(app: &AppHandle, enabled: bool) -> AppResult<()> {
     let autostart_manager = app.autolaunch();
 
     if enabled {
-        autostart_manager.enable().map_err(|e| format!("Failed to enable autostart: {}", e))?;
+        autostart_manager.enable().map_err(|e| AppError::Platform(format!("开启自启动失败: {}", e)))?;
     } else {
-        autostart_manager.disable().map_err(|e| format!("Failed to disable autostart: {}", e))?;
+        autostart_manager.disable().map_err(|e| AppError::Platform(format!("关闭自启动失败: {}", e)))?;
     }
 
     Ok(())
 }
 
-pub fn get_auto_start(app: &AppHandle) -> Result<bool, Box<dyn std::error::Error>> {
+pub fn get_auto_start(app: &AppHandle) -> AppResult<bool> {
     // 优先从系统获取真实状态
     let autostart_manager = app.autolaunch();
     match autostart_manager.is_enabled() {
@@ -53,16 +91,21 @@ pub fn get_auto_start(app: &AppHandle) -> Result<bool, Box<dyn std::error::Error
     }
 }
 
-pub fn get_next_reminder(_app: &AppHandle) -> Result<Option<Reminder>, Box<dyn std::error::Error>> {
-    let reminders = reminder_repo::get_enabled_reminders()?;
-    let current_time = Local::now().format("%H:%M").to_string();
-
-    // 找到下一个要触发的提醒
-    let next = reminders.iter()
-        .filter(|r| r.remind_time > current_time)
-        .min_by_key(|r| &r.remind_time)
-        .or_else(|| reminders.first())
-        .cloned();
+pub async fn get_next_reminder(_app: &AppHandle) -> AppResult<Option<Reminder>> {
+    // 找到下一个要触发的提醒（考虑所有重复规则及延迟、循环队列）
+    let next = manager::get_upcoming_reminders(1).await?
+        .into_iter()
+        .next()
+        .map(|item| item.reminder);
 
     Ok(next)
 }
+
+pub async fn get_upcoming_reminders(_app: &AppHandle, limit: usize) -> AppResult<Vec<UpcomingReminder>> {
+    manager::get_upcoming_reminders(limit).await
+}
+
+/// 用尚未保存的 SMTP 配置发送一封测试邮件，`to` 为空时发给发件人
+pub async fn test_smtp(_app: &AppHandle, smtp: &SmtpSettings, to: Option<&str>) -> AppResult<bool> {
+    email::test_connection(smtp, to).await
+}
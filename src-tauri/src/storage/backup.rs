@@ -0,0 +1,196 @@
+use rusqlite::{backup::Progress, Connection, DatabaseName, OpenFlags};
+use chrono::{DateTime, Local, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::error::{AppError, AppResult};
+use crate::models::backup::{BackupInfo, BackupKind};
+use crate::models::settings::AppSettings;
+use crate::storage::database::{get_connection, get_db_path};
+use crate::storage::migration;
+
+/// 备份目录（未设置时为数据库所在目录下的 backups）
+pub fn backup_dir(app: &AppHandle, settings: &AppSettings) -> AppResult<PathBuf> {
+    backup_dir_of(&get_db_path(app)?, settings.backup_dir.as_deref())
+}
+
+/// 指定数据库的备份目录，`configured` 为设置中的备份目录
+fn backup_dir_of(db_path: &Path, configured: Option<&str>) -> AppResult<PathBuf> {
+    let dir = match configured.filter(|d| !d.trim().is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => db_path
+            .parent()
+            .map(|p| p.join("backups"))
+            .ok_or_else(|| AppError::Platform("无法确定备份目录".to_string()))?,
+    };
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn backup_info(path: &Path) -> Option<BackupInfo> {
+    let file_name = path.file_name()?.to_str()?.to_string();
+    let kind = BackupKind::from_file_name(&file_name)?;
+    let metadata = fs::metadata(path).ok()?;
+    let created_at = metadata.modified().ok().map(DateTime::<Utc>::from)?;
+    Some(BackupInfo { file_name, kind, size: metadata.len(), created_at })
+}
+
+/// 列出备份目录中的备份（最新的在前）
+pub fn list_backups(app: &AppHandle, settings: &AppSettings) -> AppResult<Vec<BackupInfo>> {
+    list_backups_in(&backup_dir(app, settings)?)
+}
+
+fn list_backups_in(dir: &Path) -> AppResult<Vec<BackupInfo>> {
+    let mut backups: Vec<BackupInfo> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| backup_info(&entry.path()))
+        .collect();
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+/// 使用 SQLite 在线备份将当前数据库备份到备份目录
+pub fn create_backup(app: &AppHandle, settings: &AppSettings, kind: BackupKind) -> AppResult<BackupInfo> {
+    let dir = backup_dir(app, settings)?;
+    let path = dir.join(format!(
+        "notice-{}-{}.db",
+        kind.as_str(),
+        Local::now().format("%Y%m%d%H%M%S")
+    ));
+
+    {
+        let conn = get_connection()?.lock().unwrap();
+        conn.backup(DatabaseName::Main, &path, None)?;
+    }
+
+    backup_info(&path).ok_or_else(|| AppError::Platform(format!("备份文件未生成: {}", path.display())))
+}
+
+/// 删除超出保留数量的自动备份，返回删除的数量
+pub fn prune_auto_backups(app: &AppHandle, settings: &AppSettings) -> AppResult<usize> {
+    prune_backups(&backup_dir(app, settings)?, BackupKind::Auto, settings.backup_retention)
+}
+
+/// 删除目录中超出保留数量的指定类型备份（保留最新的），返回删除的数量
+fn prune_backups(dir: &Path, kind: BackupKind, retention: i32) -> AppResult<usize> {
+    let mut removed = 0;
+    for backup in list_backups_in(dir)?
+        .into_iter()
+        .filter(|b| b.kind == kind)
+        .skip(retention.max(1) as usize)
+    {
+        fs::remove_file(dir.join(&backup.file_name))?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// 升级数据库前备份到设置的备份目录，并清理超出保留数量的旧迁移备份，返回备份文件路径
+///
+/// 此时数据库尚未加入全局连接，备份目录和保留数量由调用方从待升级的数据库中读取。
+pub fn create_migration_backup(
+    conn: &Connection,
+    db_path: &Path,
+    from_version: u32,
+    configured_dir: Option<&str>,
+    retention: i32,
+) -> AppResult<PathBuf> {
+    let dir = backup_dir_of(db_path, configured_dir)?;
+    let path = dir.join(format!(
+        "notice-{}-v{}-{}.db",
+        BackupKind::Migration.as_str(),
+        from_version,
+        Local::now().format("%Y%m%d%H%M%S")
+    ));
+    conn.execute("VACUUM INTO ?", [path.to_string_lossy()])?;
+
+    let removed = prune_backups(&dir, BackupKind::Migration, retention)?;
+    if removed > 0 {
+        println!("[备份] 已清理旧的迁移备份 {} 个", removed);
+    }
+    Ok(path)
+}
+
+/// 根据文件名找到备份文件（只接受备份目录中的文件名，不接受路径）
+pub fn resolve_backup(app: &AppHandle, settings: &AppSettings, file_name: &str) -> AppResult<PathBuf> {
+    let not_found = || AppError::NotFound { entity: "备份", id: file_name.to_string() };
+    if file_name.contains(['/', '\\']) || BackupKind::from_file_name(file_name).is_none() {
+        return Err(not_found());
+    }
+    let path = backup_dir(app, settings)?.join(file_name);
+    if !path.is_file() {
+        return Err(not_found());
+    }
+    Ok(path)
+}
+
+/// 检查备份文件是否完整，且不是由更新版本的应用创建
+pub fn verify_backup(path: &Path) -> AppResult<()> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let integrity: String = conn.query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+    if integrity != "ok" {
+        return Err(AppError::Validation(format!("备份文件已损坏: {}", integrity)));
+    }
+    let version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let supported = migration::latest_version();
+    if version > supported {
+        return Err(AppError::SchemaTooNew { found: version, supported });
+    }
+    Ok(())
+}
+
+/// 用备份替换当前数据库：通过在线备份接口把备份内容写入已打开的连接，再升级到最新版本
+///
+/// 调用前需停止调度器，避免恢复过程中读写数据库。
+pub fn restore_from(app: &AppHandle, path: &Path) -> AppResult<()> {
+    let db_path = get_db_path(app)?;
+    let mut conn = get_connection()?.lock().unwrap();
+    conn.restore(DatabaseName::Main, path, None::<fn(Progress)>)?;
+    migration::run(&mut conn, &db_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn migration_backup_uses_configured_dir_and_retention() {
+        let root = std::env::temp_dir().join(format!("dingdong-backup-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let backup_dir = root.join("custom");
+        fs::create_dir_all(&backup_dir).unwrap();
+
+        // 两个更早的迁移备份和一个自动备份
+        let existing = [
+            ("notice-migration-v1-20250101000000.db", 20),
+            ("notice-migration-v2-20250201000000.db", 10),
+            ("notice-auto-20250101000000.db", 30),
+        ];
+        for (name, days_ago) in existing {
+            let file = fs::File::create(backup_dir.join(name)).unwrap();
+            file.set_modified(SystemTime::now() - Duration::from_secs(days_ago * 86400)).unwrap();
+        }
+
+        let conn = Connection::open(root.join("notice.db")).unwrap();
+        conn.execute_batch("CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (1);").unwrap();
+        let path = create_migration_backup(&conn, &root.join("notice.db"), 3, backup_dir.to_str(), 2).unwrap();
+
+        assert_eq!(path.parent(), Some(backup_dir.as_path()));
+        assert!(!root.join("backups").exists());
+        let mut names: Vec<String> = list_backups_in(&backup_dir).unwrap().into_iter().map(|b| b.file_name).collect();
+        names.sort();
+        assert_eq!(names.len(), 3);
+        assert_eq!(names[0], "notice-auto-20250101000000.db");
+        assert!(names[1].starts_with("notice-migration-v2-"));
+        assert!(names[2].starts_with("notice-migration-v3-"));
+
+        let restored = Connection::open(&path).unwrap();
+        let x: i64 = restored.query_row("SELECT x FROM t", [], |row| row.get(0)).unwrap();
+        assert_eq!(x, 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
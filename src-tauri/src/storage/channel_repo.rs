@@ -0,0 +1,165 @@
+use rusqlite::{params, Row};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::models::channel::{Channel, ChannelKind, CreateChannelRequest, UpdateChannelRequest};
+use crate::error::{AppError, AppResult};
+use crate::storage::database::get_connection;
+
+/// 提醒中引用渠道的列
+const REMINDER_CHANNEL_COLUMNS: [&str; 3] = ["trigger_channels", "complete_channels", "timeout_channels"];
+
+fn row_to_channel(row: &Row) -> rusqlite::Result<Channel> {
+    let kind: String = row.get("kind")?;
+    let config: String = row.get("config")?;
+
+    Ok(Channel {
+        id: Some(row.get("id")?),
+        uuid: row.get("uuid")?,
+        name: row.get("name")?,
+        kind: ChannelKind::from_str(&kind),
+        config: serde_json::from_str(&config).unwrap_or_default(),
+        is_enabled: row.get::<_, i32>("is_enabled")? == 1,
+        allow_during_dnd: row.get::<_, i32>("allow_during_dnd")? == 1,
+        created_at: DateTime::parse_from_rfc3339(&row.get::<_, String>("created_at")?)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        updated_at: DateTime::parse_from_rfc3339(&row.get::<_, String>("updated_at")?)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+    })
+}
+
+pub fn get_all() -> AppResult<Vec<Channel>> {
+    let conn = get_connection()?.lock().unwrap();
+    let mut stmt = conn.prepare("SELECT * FROM notification_channels ORDER BY id ASC")?;
+    let channels = stmt.query_map([], row_to_channel)?
+        .filter_map(|c| c.ok())
+        .collect();
+    Ok(channels)
+}
+
+pub fn get_by_uuid(uuid: &str) -> AppResult<Option<Channel>> {
+    let conn = get_connection()?.lock().unwrap();
+    let mut stmt = conn.prepare("SELECT * FROM notification_channels WHERE uuid = ?")?;
+    let mut rows = stmt.query_map(params![uuid], row_to_channel)?;
+    Ok(rows.next().transpose()?)
+}
+
+pub fn create(request: CreateChannelRequest) -> AppResult<Channel> {
+    let conn = get_connection()?.lock().unwrap();
+    let uuid = Uuid::new_v4().to_string();
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        "INSERT INTO notification_channels (uuid, name, kind, config, is_enabled, allow_during_dnd, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        params![
+            uuid,
+            request.name,
+            request.kind.as_str(),
+            request.config.to_string(),
+            request.is_enabled.unwrap_or(true) as i32,
+            request.allow_during_dnd.unwrap_or(true) as i32,
+            now,
+            now,
+        ],
+    )?;
+
+    drop(conn);
+    get_by_uuid(&uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
+}
+
+pub fn update(request: UpdateChannelRequest) -> AppResult<Channel> {
+    let conn = get_connection()?.lock().unwrap();
+
+    let mut updates = vec!["updated_at = ?"];
+    let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(Utc::now().to_rfc3339())];
+
+    if let Some(ref name) = request.name {
+        updates.push("name = ?");
+        params_vec.push(Box::new(name.clone()));
+    }
+    if let Some(ref config) = request.config {
+        updates.push("config = ?");
+        params_vec.push(Box::new(config.to_string()));
+    }
+    if let Some(is_enabled) = request.is_enabled {
+        updates.push("is_enabled = ?");
+        params_vec.push(Box::new(is_enabled as i32));
+    }
+    if let Some(allow_during_dnd) = request.allow_during_dnd {
+        updates.push("allow_during_dnd = ?");
+        params_vec.push(Box::new(allow_during_dnd as i32));
+    }
+
+    params_vec.push(Box::new(request.uuid.clone()));
+
+    let sql = format!("UPDATE notification_channels SET {} WHERE uuid = ?", updates.join(", "));
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
+    conn.execute(&sql, params_refs.as_slice())?;
+
+    drop(conn);
+    get_by_uuid(&request.uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
+}
+
+/// 删除渠道，并从引用它的提醒中移除
+pub fn delete(uuid: &str) -> AppResult<()> {
+    let mut conn = get_connection()?.lock().unwrap();
+    let tx = conn.transaction()?;
+
+    // 不修改 updated_at：补发错过的提醒以修改时间为起点，移除渠道不应影响补发
+    for column in REMINDER_CHANNEL_COLUMNS {
+        tx.execute(
+            &format!(
+                "UPDATE reminders SET
+                 {column} = (SELECT json_group_array(value) FROM json_each(reminders.{column}) WHERE value != ?1),
+                 version = version + 1, sync_status = 'pending'
+                 WHERE EXISTS (SELECT 1 FROM json_each(reminders.{column}) WHERE value = ?1)"
+            ),
+            params![uuid],
+        )?;
+    }
+    tx.execute("DELETE FROM notification_channels WHERE uuid = ?", params![uuid])?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::reminder::CreateReminderRequest;
+    use crate::storage::database::init_test_database;
+    use crate::storage::reminder_repo;
+
+    #[test]
+    fn delete_strips_channel_without_touching_updated_at() {
+        init_test_database();
+        let channel = create(CreateChannelRequest {
+            name: "待删除".to_string(),
+            kind: ChannelKind::Webhook,
+            config: serde_json::json!({ "url": "http://127.0.0.1:1/hook" }),
+            is_enabled: None,
+            allow_during_dnd: None,
+        })
+        .unwrap();
+        let request: CreateReminderRequest = serde_json::from_value(serde_json::json!({
+            "title": "引用渠道",
+            "remindTime": "09:00",
+            "remindType": "daily",
+            "triggerChannels": [channel.uuid, "other"],
+            "timeoutChannels": [channel.uuid],
+        }))
+        .unwrap();
+        let reminder = reminder_repo::create(request).unwrap();
+
+        delete(&channel.uuid).unwrap();
+
+        let updated = reminder_repo::get_by_uuid(&reminder.uuid).unwrap().unwrap();
+        assert_eq!(updated.trigger_channels, vec!["other".to_string()]);
+        assert!(updated.timeout_channels.is_empty());
+        assert_eq!(updated.updated_at, reminder.updated_at);
+        assert_eq!(updated.version, reminder.version + 2);
+        assert!(get_by_uuid(&channel.uuid).unwrap().is_none());
+    }
+}
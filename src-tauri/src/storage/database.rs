@@ -1,32 +1,55 @@
 use rusqlite::Connection;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use once_cell::sync::OnceCell;
 use tauri::{AppHandle, Manager};
 
+use crate::error::{AppError, AppResult};
+use crate::storage::migration;
+
 static DATABASE: OnceCell<Mutex<Connection>> = OnceCell::new();
 
-pub fn get_db_path(app: &AppHandle) -> PathBuf {
-    let app_dir = app.path().app_data_dir().expect("Failed to get app data dir");
-    fs::create_dir_all(&app_dir).expect("Failed to create app data dir");
-    app_dir.join("notice.db")
+pub fn get_db_path(app: &AppHandle) -> AppResult<PathBuf> {
+    let app_dir = app.path().app_data_dir()?;
+    fs::create_dir_all(&app_dir)?;
+    Ok(app_dir.join("notice.db"))
 }
 
-pub fn init_database(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    let db_path = get_db_path(app);
-    let conn = Connection::open(&db_path)?;
+/// 打开数据库并升级表结构
+fn open(db_path: &Path) -> AppResult<Connection> {
+    let mut conn = Connection::open(db_path)?;
+    migration::run(&mut conn, db_path)?;
+    Ok(conn)
+}
 
-    // 创建表
-    conn.execute_batch(include_str!("../../migrations/001_init.sql"))?;
+pub fn init_database(app: &AppHandle) -> AppResult<()> {
+    let conn = open(&get_db_path(app)?)?;
 
     DATABASE
         .set(Mutex::new(conn))
-        .map_err(|_| "Database already initialized")?;
+        .map_err(|_| AppError::Platform("数据库已初始化".to_string()))?;
 
     Ok(())
 }
 
-pub fn get_connection() -> &'static Mutex<Connection> {
-    DATABASE.get().expect("Database not initialized")
+/// 测试用：在临时目录中创建数据库（同一测试进程内共用）
+#[cfg(test)]
+pub fn init_test_database() {
+    DATABASE
+        .get_or_try_init(|| {
+            let dir = std::env::temp_dir().join(format!("dingdong-test-{}", std::process::id()));
+            fs::create_dir_all(&dir)?;
+            let db_path = dir.join("notice.db");
+            let _ = fs::remove_file(&db_path);
+            open(&db_path).map(Mutex::new)
+        })
+        .expect("创建测试数据库失败");
+}
+
+/// 获取数据库连接（尚未初始化时返回错误）
+pub fn get_connection() -> AppResult<&'static Mutex<Connection>> {
+    DATABASE
+        .get()
+        .ok_or_else(|| AppError::Platform("数据库尚未初始化".to_string()))
 }
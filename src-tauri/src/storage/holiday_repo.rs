@@ -0,0 +1,68 @@
+use rusqlite::params;
+use chrono::{NaiveDate, Utc};
+use crate::models::holiday::{HolidayDay, HolidayKind, HolidaySource};
+use crate::error::AppResult;
+use crate::storage::database::get_connection;
+
+/// 用指定来源的数据整体替换（同一来源的旧数据会被清除）
+pub fn replace_source(source: HolidaySource, days: &[HolidayDay]) -> AppResult<()> {
+    let mut conn = get_connection()?.lock().unwrap();
+    let now = Utc::now().to_rfc3339();
+    let tx = conn.transaction()?;
+
+    tx.execute("DELETE FROM holidays WHERE source = ?", params![source.as_str()])?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT OR REPLACE INTO holidays (date, kind, name, source, updated_at) VALUES (?, ?, ?, ?, ?)",
+        )?;
+        for day in days {
+            stmt.execute(params![
+                day.date.format("%Y-%m-%d").to_string(),
+                day.kind.as_str(),
+                day.name,
+                source.as_str(),
+                now,
+            ])?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+/// 删除指定来源的数据
+pub fn clear_source(source: HolidaySource) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
+    conn.execute("DELETE FROM holidays WHERE source = ?", params![source.as_str()])?;
+    Ok(())
+}
+
+/// 获取生效的日历数据（同一天用户导入的数据覆盖内置数据），可按年份过滤
+pub fn get_effective(year: Option<i32>) -> AppResult<Vec<HolidayDay>> {
+    let conn = get_connection()?.lock().unwrap();
+    let mut stmt = conn.prepare(
+        "SELECT date, kind, name FROM holidays h
+         WHERE (?1 IS NULL OR substr(date, 1, 4) = printf('%04d', ?1))
+         AND NOT (source = 'bundled' AND EXISTS (
+             SELECT 1 FROM holidays u WHERE u.date = h.date AND u.source = 'user'
+         ))
+         ORDER BY date ASC",
+    )?;
+
+    let days = stmt.query_map(params![year], |row| {
+        let date: String = row.get("date")?;
+        let kind: String = row.get("kind")?;
+        Ok((date, kind, row.get::<_, String>("name")?))
+    })?
+        .filter_map(|row| row.ok())
+        .filter_map(|(date, kind, name)| {
+            NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok().map(|date| HolidayDay {
+                date,
+                kind: HolidayKind::from_str(&kind),
+                name,
+            })
+        })
+        .collect();
+
+    Ok(days)
+}
@@ -0,0 +1,156 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+
+use crate::error::{AppError, AppResult};
+use crate::models::settings::AppSettings;
+use crate::storage::backup;
+
+/// 数据库迁移：按版本号顺序执行，每个迁移在独立事务中完成并记录到 `PRAGMA user_version`
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub up: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+/// 所有迁移（版本号递增，新增迁移追加到末尾）
+///
+/// 引入版本号之前的数据库 `user_version` 为 0，新增列的迁移会先检查列是否存在，
+/// 因此旧数据库可以从头执行全部迁移。
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, name: "init", up: |c| c.execute_batch(include_str!("../../migrations/001_init.sql")) },
+    Migration { version: 2, name: "scheduler_queue", up: |c| c.execute_batch(include_str!("../../migrations/002_scheduler_queue.sql")) },
+    Migration { version: 3, name: "reminder_date", up: |c| add_columns(c, "reminders", "remind_date", include_str!("../../migrations/003_reminder_date.sql")) },
+    Migration { version: 4, name: "reminder_times", up: |c| add_columns(c, "reminders", "remind_times", include_str!("../../migrations/004_reminder_times.sql")) },
+    Migration { version: 5, name: "log_slot", up: |c| add_columns(c, "reminder_logs", "slot", include_str!("../../migrations/005_log_slot.sql")) },
+    Migration { version: 6, name: "recurrence_rule", up: |c| add_columns(c, "reminders", "recurrence_rule", include_str!("../../migrations/006_recurrence_rule.sql")) },
+    Migration { version: 7, name: "interval", up: |c| add_columns(c, "reminders", "interval_value", include_str!("../../migrations/007_interval.sql")) },
+    Migration { version: 8, name: "holidays", up: |c| c.execute_batch(include_str!("../../migrations/008_holidays.sql")) },
+    Migration { version: 9, name: "skip_holidays", up: |c| add_columns(c, "reminders", "skip_holidays", include_str!("../../migrations/009_skip_holidays.sql")) },
+    Migration { version: 10, name: "lunar", up: |c| add_columns(c, "reminders", "lunar_day", include_str!("../../migrations/010_lunar.sql")) },
+    Migration { version: 11, name: "yearly", up: |c| add_columns(c, "reminders", "yearly_month", include_str!("../../migrations/011_yearly.sql")) },
+    Migration { version: 12, name: "monthly_mode", up: |c| add_columns(c, "reminders", "monthly_mode", include_str!("../../migrations/012_monthly_mode.sql")) },
+    Migration { version: 13, name: "time_zone", up: |c| add_columns(c, "reminders", "time_zone", include_str!("../../migrations/013_time_zone.sql")) },
+    Migration { version: 14, name: "reminder_limits", up: |c| add_columns(c, "reminders", "max_occurrences", include_str!("../../migrations/014_reminder_limits.sql")) },
+    Migration { version: 15, name: "paused_until", up: |c| add_columns(c, "reminders", "paused_until", include_str!("../../migrations/015_paused_until.sql")) },
+    Migration { version: 16, name: "critical", up: |c| add_columns(c, "reminders", "is_critical", include_str!("../../migrations/016_critical.sql")) },
+    Migration { version: 17, name: "notification_channels", up: migrate_notification_channels },
+];
+
+/// 当前应用支持的数据库版本
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |m| m.version)
+}
+
+fn column_exists(conn: &Connection, table: &str, column: &str) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt.query_map([], |row| row.get::<_, String>("name"))?
+        .filter_map(|name| name.ok())
+        .any(|name| name == column);
+    Ok(exists)
+}
+
+/// 执行新增列的迁移（`column` 已存在时跳过，兼容引入版本号之前的数据库）
+fn add_columns(conn: &Connection, table: &str, column: &str, sql: &str) -> rusqlite::Result<()> {
+    if !column_exists(conn, table, column)? {
+        conn.execute_batch(sql)?;
+    }
+
+    Ok(())
+}
+
+/// 读取 JSON 格式的设置项
+fn setting<T: serde::de::DeserializeOwned>(conn: &Connection, key: &str) -> rusqlite::Result<Option<T>> {
+    let value: Option<String> = conn
+        .query_row("SELECT value FROM settings WHERE key = ?", [key], |row| row.get(0))
+        .optional()?;
+    Ok(value.and_then(|v| serde_json::from_str(&v).ok()))
+}
+
+/// 引入通知渠道：原设置中的 Server酱 转为一个渠道，
+/// 提醒的 `notify_on_trigger/complete/timeout` 开关转为对应事件的渠道列表
+///
+/// 旧的开关列和 Server酱 设置保留不删（不再用于发送），兼容旧版前端和降级。
+fn migrate_notification_channels(conn: &Connection) -> rusqlite::Result<()> {
+    if column_exists(conn, "reminders", "trigger_channels")? {
+        return Ok(());
+    }
+    conn.execute_batch(include_str!("../../migrations/017_notification_channels.sql"))?;
+
+    let sendkey = setting::<String>(conn, "serverchan_sendkey")?.filter(|k| !k.trim().is_empty());
+    if let Some(sendkey) = sendkey {
+        let uuid = uuid::Uuid::new_v4().to_string();
+        let now = chrono::Utc::now().to_rfc3339();
+        let config = serde_json::json!({
+            "domain": setting::<String>(conn, "serverchan_domain")?,
+            "sendkey": sendkey,
+        });
+        let allow_during_dnd = setting::<bool>(conn, "dnd_allow_serverchan")?.unwrap_or(true);
+
+        conn.execute(
+            "INSERT INTO notification_channels (uuid, name, kind, config, is_enabled, allow_during_dnd, created_at, updated_at)
+             VALUES (?, 'Server酱', 'serverchan', ?, 1, ?, ?, ?)",
+            params![uuid, config.to_string(), allow_during_dnd as i32, now, now],
+        )?;
+
+        let channels = serde_json::json!([uuid]).to_string();
+        for event in ["trigger", "complete", "timeout"] {
+            conn.execute(
+                &format!("UPDATE reminders SET {event}_channels = ? WHERE notify_on_{event} = 1"),
+                [&channels],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn user_version(conn: &Connection) -> rusqlite::Result<u32> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// 数据库是否为新建（还没有任何表）
+fn is_empty(conn: &Connection) -> rusqlite::Result<bool> {
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'", [], |row| row.get(0))?;
+    Ok(count == 0)
+}
+
+/// 迁移前按设置的备份目录和保留数量备份数据库，返回备份文件路径
+fn backup_before_migration(conn: &Connection, db_path: &Path, from_version: u32) -> AppResult<PathBuf> {
+    // 旧版本数据库可能还没有这些设置项
+    let backup_dir: Option<String> = setting(conn, "backup_dir").ok().flatten();
+    let retention = setting(conn, "backup_retention")
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| AppSettings::default().backup_retention);
+    backup::create_migration_backup(conn, db_path, from_version, backup_dir.as_deref(), retention)
+}
+
+/// 将数据库升级到最新版本
+///
+/// 数据库版本高于应用支持的版本时（由新版本应用创建）拒绝打开，避免旧版本写坏数据。
+pub fn run(conn: &mut Connection, db_path: &Path) -> AppResult<()> {
+    let current = user_version(conn)?;
+    let latest = latest_version();
+
+    if current > latest {
+        return Err(AppError::SchemaTooNew { found: current, supported: latest });
+    }
+    if current == latest {
+        return Ok(());
+    }
+
+    if !is_empty(conn)? {
+        let backup_path = backup_before_migration(conn, db_path, current)?;
+        println!("[数据库] 迁移前已备份到 {}", backup_path.display());
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = conn.transaction()?;
+        (migration.up)(&tx)?;
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+        println!("[数据库] 已应用迁移 {:03}_{}", migration.version, migration.name);
+    }
+
+    Ok(())
+}
@@ -1,4 +1,9 @@
+pub mod backup;
 pub mod database;
+pub mod migration;
 pub mod reminder_repo;
 pub mod reminder_log_repo;
 pub mod settings_repo;
+pub mod scheduler_queue_repo;
+pub mod holiday_repo;
+pub mod channel_repo;
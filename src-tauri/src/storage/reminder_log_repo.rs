@@ -1,8 +1,10 @@
 use rusqlite::{params, Row};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::models::digest::DigestEntry;
 use crate::models::reminder::SyncStatus;
 use crate::models::reminder_log::{ReminderLog, LogAction};
+use crate::error::{AppError, AppResult};
 use crate::storage::database::get_connection;
 
 fn row_to_log(row: &Row) -> rusqlite::Result<ReminderLog> {
@@ -24,6 +26,7 @@ fn row_to_log(row: &Row) -> rusqlite::Result<ReminderLog> {
             .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
             .map(|dt| dt.with_timezone(&Utc)),
         note: row.get("note")?,
+        slot: row.get("slot")?,
         created_at: DateTime::parse_from_rfc3339(&row.get::<_, String>("created_at")?)
             .map(|dt| dt.with_timezone(&Utc))
             .unwrap_or_else(|_| Utc::now()),
@@ -41,8 +44,8 @@ pub fn create(
     triggered_at: DateTime<Utc>,
     snooze_until: Option<DateTime<Utc>>,
     note: Option<String>,
-) -> Result<ReminderLog, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+) -> AppResult<ReminderLog> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
     let uuid = Uuid::new_v4().to_string();
 
@@ -64,19 +67,23 @@ pub fn create(
     )?;
 
     drop(conn);
-    get_by_uuid(&uuid).map(|opt| opt.expect("Just created log not found"))
+    get_by_uuid(&uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
 }
 
-/// 创建触发日志（不带 action_at）
-pub fn create_triggered(reminder_uuid: &str, note: Option<String>) -> Result<ReminderLog, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+/// 创建触发日志（不带 action_at），`slot` 为触发的时间点
+pub fn create_triggered(
+    reminder_uuid: &str,
+    note: Option<String>,
+    slot: Option<String>,
+) -> AppResult<ReminderLog> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
     let uuid = Uuid::new_v4().to_string();
 
     conn.execute(
         "INSERT INTO reminder_logs (
-            uuid, reminder_uuid, action, triggered_at, created_at, note, sync_status
-        ) VALUES (?, ?, ?, ?, ?, ?, 'pending')",
+            uuid, reminder_uuid, action, triggered_at, created_at, note, slot, sync_status
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, 'pending')",
         params![
             uuid,
             reminder_uuid,
@@ -84,16 +91,17 @@ pub fn create_triggered(reminder_uuid: &str, note: Option<String>) -> Result<Rem
             now.to_rfc3339(),
             now.to_rfc3339(),
             note,
+            slot,
         ],
     )?;
 
     drop(conn);
-    get_by_uuid(&uuid).map(|opt| opt.expect("Just created log not found"))
+    get_by_uuid(&uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
 }
 
 /// 根据 UUID 获取日志
-pub fn get_by_uuid(uuid: &str) -> Result<Option<ReminderLog>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_by_uuid(uuid: &str) -> AppResult<Option<ReminderLog>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare("SELECT * FROM reminder_logs WHERE uuid = ?")?;
 
     let mut rows = stmt.query(params![uuid])?;
@@ -104,8 +112,8 @@ pub fn get_by_uuid(uuid: &str) -> Result<Option<ReminderLog>, rusqlite::Error> {
 }
 
 /// 获取某个提醒的所有日志
-pub fn get_by_reminder_uuid(reminder_uuid: &str) -> Result<Vec<ReminderLog>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_by_reminder_uuid(reminder_uuid: &str) -> AppResult<Vec<ReminderLog>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare(
         "SELECT * FROM reminder_logs WHERE reminder_uuid = ? ORDER BY created_at DESC"
     )?;
@@ -122,8 +130,8 @@ pub fn query(
     start_date: Option<&str>,
     end_date: Option<&str>,
     limit: Option<i32>,
-) -> Result<Vec<ReminderLog>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+) -> AppResult<Vec<ReminderLog>> {
+    let conn = get_connection()?.lock().unwrap();
 
     let mut conditions = vec!["1=1".to_string()];
     let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![];
@@ -161,8 +169,8 @@ pub fn query(
 }
 
 /// 获取提醒最近一次触发的日志（未完成的）
-pub fn get_latest_triggered(reminder_uuid: &str) -> Result<Option<ReminderLog>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_latest_triggered(reminder_uuid: &str) -> AppResult<Option<ReminderLog>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare(
         "SELECT * FROM reminder_logs
          WHERE reminder_uuid = ? AND action = 'triggered'
@@ -177,22 +185,48 @@ pub fn get_latest_triggered(reminder_uuid: &str) -> Result<Option<ReminderLog>,
 }
 
 /// 删除日志
-pub fn delete(uuid: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn delete(uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     conn.execute("DELETE FROM reminder_logs WHERE uuid = ?", params![uuid])?;
     Ok(())
 }
 
 /// 删除提醒的所有日志
-pub fn delete_by_reminder_uuid(reminder_uuid: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn delete_by_reminder_uuid(reminder_uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     conn.execute("DELETE FROM reminder_logs WHERE reminder_uuid = ?", params![reminder_uuid])?;
     Ok(())
 }
 
+/// 删除提醒在指定时间之后的跳过记录（取消或重新暂停时撤销尚未到来的跳过）
+pub fn delete_skipped_after(reminder_uuid: &str, after: DateTime<Utc>) -> AppResult<usize> {
+    let conn = get_connection()?.lock().unwrap();
+    let deleted = conn.execute(
+        "DELETE FROM reminder_logs
+         WHERE reminder_uuid = ? AND action = 'skipped' AND julianday(triggered_at) > julianday(?)",
+        params![reminder_uuid, after.to_rfc3339()],
+    )?;
+    Ok(deleted)
+}
+
+/// 获取提醒的触发次数（不含延迟、循环等重复提醒）
+pub fn count_triggered(reminder_uuid: &str) -> AppResult<i32> {
+    let conn = get_connection()?.lock().unwrap();
+
+    let count: i32 = conn.query_row(
+        "SELECT COUNT(*) FROM reminder_logs
+         WHERE reminder_uuid = ? AND action = 'triggered'
+         AND (note IS NULL OR note != '重复提醒')",
+        params![reminder_uuid],
+        |row| row.get(0),
+    )?;
+
+    Ok(count)
+}
+
 /// 获取今天某个提醒的完成次数
-pub fn get_today_completed_count(reminder_uuid: &str) -> Result<i32, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_today_completed_count(reminder_uuid: &str) -> AppResult<i32> {
+    let conn = get_connection()?.lock().unwrap();
     let today = chrono::Local::now().format("%Y-%m-%d").to_string();
 
     let count: i32 = conn.query_row(
@@ -205,3 +239,45 @@ pub fn get_today_completed_count(reminder_uuid: &str) -> Result<i32, rusqlite::E
 
     Ok(count)
 }
+
+/// 按提醒统计一段时间内（按记录创建时间，含 `since` 不含 `until`）各类操作的次数
+pub fn summarize(since: DateTime<Utc>, until: DateTime<Utc>) -> AppResult<Vec<DigestEntry>> {
+    let conn = get_connection()?.lock().unwrap();
+
+    let mut stmt = conn.prepare(
+        "SELECT l.reminder_uuid, r.title, l.action, COUNT(*) AS count
+         FROM reminder_logs l
+         JOIN reminders r ON r.uuid = l.reminder_uuid
+         WHERE julianday(l.created_at) >= julianday(?1) AND julianday(l.created_at) < julianday(?2)
+         AND (l.note IS NULL OR l.note != '重复提醒')
+         GROUP BY l.reminder_uuid, l.action
+         ORDER BY r.sort_order, l.reminder_uuid",
+    )?;
+    let rows = stmt.query_map(params![since.to_rfc3339(), until.to_rfc3339()], |row| {
+        Ok((
+            row.get::<_, String>("reminder_uuid")?,
+            row.get::<_, String>("title")?,
+            row.get::<_, String>("action")?,
+            row.get::<_, i64>("count")?,
+        ))
+    })?;
+
+    let mut entries: Vec<DigestEntry> = Vec::new();
+    for row in rows {
+        let (reminder_uuid, title, action, count) = row?;
+        if entries.last().map_or(true, |e| e.reminder_uuid != reminder_uuid) {
+            entries.push(DigestEntry { reminder_uuid, title, ..Default::default() });
+        }
+        let entry = entries.last_mut().unwrap();
+        match LogAction::from_str(&action) {
+            LogAction::Triggered => entry.triggered += count,
+            LogAction::Completed => entry.completed += count,
+            LogAction::Dismissed => entry.dismissed += count,
+            LogAction::TimedOut => entry.timed_out += count,
+            LogAction::Missed => entry.missed += count,
+            _ => {}
+        }
+    }
+
+    Ok(entries)
+}
@@ -1,10 +1,11 @@
 use rusqlite::{params, Row};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 use crate::models::reminder::{
     Reminder, CreateReminderRequest, UpdateReminderRequest,
-    RemindType, SyncStatus, ReminderLink,
+    RemindType, SyncStatus, IntervalUnit, LunarLeapPolicy, LeapDayPolicy, MonthlyMode, DstPolicy,
 };
+use crate::error::{AppError, AppResult};
 use crate::storage::database::get_connection;
 
 fn row_to_reminder(row: &Row) -> rusqlite::Result<Reminder> {
@@ -13,6 +14,7 @@ fn row_to_reminder(row: &Row) -> rusqlite::Result<Reminder> {
     let links_json: Option<String> = row.get("links")?;
     let weekdays_json: Option<String> = row.get("weekdays")?;
     let monthdays_json: Option<String> = row.get("monthdays").unwrap_or(None);
+    let remind_times_json: Option<String> = row.get("remind_times")?;
 
     Ok(Reminder {
         id: Some(row.get("id")?),
@@ -22,18 +24,60 @@ fn row_to_reminder(row: &Row) -> rusqlite::Result<Reminder> {
         content: row.get("content")?,
         links: links_json.and_then(|s| serde_json::from_str(&s).ok()),
         remind_time: row.get("remind_time")?,
+        remind_times: remind_times_json.and_then(|s| serde_json::from_str(&s).ok()),
         remind_type: RemindType::from_str(&remind_type_str),
         weekdays: weekdays_json.and_then(|s| serde_json::from_str(&s).ok()),
         monthdays: monthdays_json.and_then(|s| serde_json::from_str(&s).ok()),
+        remind_date: row.get::<_, Option<String>>("remind_date")?
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        recurrence_rule: row.get("recurrence_rule")?,
+        starts_on: row.get::<_, Option<String>>("starts_on")?
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        ends_on: row.get::<_, Option<String>>("ends_on")?
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        max_occurrences: row.get("max_occurrences")?,
+        interval_value: row.get("interval_value")?,
+        interval_unit: row.get::<_, Option<String>>("interval_unit")?
+            .map(|s| IntervalUnit::from_str(&s)),
+        anchor_at: row.get::<_, Option<String>>("anchor_at")?
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)),
+        window_start: row.get("window_start")?,
+        window_end: row.get("window_end")?,
+        skip_holidays: row.get::<_, i32>("skip_holidays")? == 1,
+        paused_until: row.get::<_, Option<String>>("paused_until")?
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)),
+        time_zone: row.get("time_zone")?,
+        dst_policy: row.get::<_, Option<String>>("dst_policy")?
+            .map(|s| DstPolicy::from_str(&s)),
+        monthly_mode: row.get::<_, Option<String>>("monthly_mode")?
+            .map(|s| MonthlyMode::from_str(&s)),
+        month_week: row.get("month_week")?,
+        month_weekday: row.get("month_weekday")?,
+        days_before_end: row.get("days_before_end")?,
+        yearly_month: row.get("yearly_month")?,
+        yearly_day: row.get("yearly_day")?,
+        leap_day_policy: row.get::<_, Option<String>>("leap_day_policy")?
+            .map(|s| LeapDayPolicy::from_str(&s)),
+        lunar_month: row.get("lunar_month")?,
+        lunar_day: row.get("lunar_day")?,
+        lunar_leap_policy: row.get::<_, Option<String>>("lunar_leap_policy")?
+            .map(|s| LunarLeapPolicy::from_str(&s)),
+        lunar_days_before: row.get("lunar_days_before")?,
         is_enabled: row.get::<_, i32>("is_enabled")? == 1,
         repeat_on_close: row.get::<_, i32>("repeat_on_close")? == 1,
         repeat_interval: row.get("repeat_interval")?,
         is_loop: row.get::<_, i32>("is_loop")? == 1,
         loop_interval: row.get("loop_interval")?,
-        notify_on_trigger: row.get::<_, i32>("notify_on_trigger")? == 1,
-        notify_on_complete: row.get::<_, i32>("notify_on_complete")? == 1,
-        notify_on_timeout: row.get::<_, i32>("notify_on_timeout")? == 1,
+        trigger_channels: serde_json::from_str(&row.get::<_, String>("trigger_channels")?).unwrap_or_default(),
+        complete_channels: serde_json::from_str(&row.get::<_, String>("complete_channels")?).unwrap_or_default(),
+        timeout_channels: serde_json::from_str(&row.get::<_, String>("timeout_channels")?).unwrap_or_default(),
         timeout_minutes: row.get("timeout_minutes")?,
+        notify_on_trigger: false,
+        notify_on_complete: false,
+        notify_on_timeout: false,
+        is_critical: row.get::<_, i32>("is_critical")? == 1,
         last_triggered_at: row.get::<_, Option<String>>("last_triggered_at")?
             .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
             .map(|dt| dt.with_timezone(&Utc)),
@@ -58,8 +102,8 @@ fn row_to_reminder(row: &Row) -> rusqlite::Result<Reminder> {
     })
 }
 
-pub fn get_all() -> Result<Vec<Reminder>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_all() -> AppResult<Vec<Reminder>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare(
         "SELECT * FROM reminders WHERE deleted_at IS NULL ORDER BY sort_order ASC, created_at DESC"
     )?;
@@ -70,8 +114,8 @@ pub fn get_all() -> Result<Vec<Reminder>, rusqlite::Error> {
     Ok(reminders)
 }
 
-pub fn get_by_uuid(uuid: &str) -> Result<Option<Reminder>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_by_uuid(uuid: &str) -> AppResult<Option<Reminder>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare(
         "SELECT * FROM reminders WHERE uuid = ? AND deleted_at IS NULL"
     )?;
@@ -83,50 +127,81 @@ pub fn get_by_uuid(uuid: &str) -> Result<Option<Reminder>, rusqlite::Error> {
     }
 }
 
-pub fn create(request: CreateReminderRequest) -> Result<Reminder, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn create(request: CreateReminderRequest) -> AppResult<Reminder> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
     let uuid = Uuid::new_v4().to_string();
 
     let links_json = request.links.as_ref().map(|l| serde_json::to_string(l).unwrap());
     let weekdays_json = request.weekdays.as_ref().map(|w| serde_json::to_string(w).unwrap());
     let monthdays_json = request.monthdays.as_ref().map(|m| serde_json::to_string(m).unwrap());
+    let remind_times_json = request.remind_times.as_ref().map(|t| serde_json::to_string(t).unwrap());
 
     conn.execute(
         "INSERT INTO reminders (
-            uuid, title, content, links, remind_time, remind_type, weekdays, monthdays,
-            is_enabled, repeat_on_close, repeat_interval, is_loop, loop_interval,
-            notify_on_trigger, notify_on_complete, notify_on_timeout, timeout_minutes,
+            uuid, title, content, links, remind_time, remind_times, remind_type, weekdays, monthdays,
+            remind_date, recurrence_rule, interval_value, interval_unit, anchor_at,
+            window_start, window_end, skip_holidays, lunar_month, lunar_day, lunar_leap_policy,
+            lunar_days_before, yearly_month, yearly_day, leap_day_policy,
+            monthly_mode, month_week, month_weekday, days_before_end, time_zone, dst_policy,
+            starts_on, ends_on, max_occurrences, is_enabled, repeat_on_close, repeat_interval, is_loop, loop_interval,
+            trigger_channels, complete_channels, timeout_channels, timeout_minutes, is_critical,
             sort_order, created_at, updated_at, version, sync_status
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, 1, ?, ?, ?, ?, ?, ?, ?, ?, 0, ?, ?, 1, 'pending')",
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 1, ?, ?, ?, ?, ?, ?, ?, ?, ?, 0, ?, ?, 1, 'pending')",
         params![
             uuid,
             request.title,
             request.content.unwrap_or_default(),
             links_json,
             request.remind_time,
+            remind_times_json,
             request.remind_type.unwrap_or_default().as_str(),
             weekdays_json,
             monthdays_json,
+            request.remind_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            request.recurrence_rule,
+            request.interval_value,
+            request.interval_unit.as_ref().map(|u| u.as_str()),
+            request.anchor_at.map(|dt| dt.to_rfc3339()),
+            request.window_start,
+            request.window_end,
+            request.skip_holidays.unwrap_or(false) as i32,
+            request.lunar_month,
+            request.lunar_day,
+            request.lunar_leap_policy.as_ref().map(|p| p.as_str()),
+            request.lunar_days_before,
+            request.yearly_month,
+            request.yearly_day,
+            request.leap_day_policy.as_ref().map(|p| p.as_str()),
+            request.monthly_mode.as_ref().map(|m| m.as_str()),
+            request.month_week,
+            request.month_weekday,
+            request.days_before_end,
+            request.time_zone,
+            request.dst_policy.as_ref().map(|p| p.as_str()),
+            request.starts_on.map(|d| d.format("%Y-%m-%d").to_string()),
+            request.ends_on.map(|d| d.format("%Y-%m-%d").to_string()),
+            request.max_occurrences,
             request.repeat_on_close.unwrap_or(false) as i32,
             request.repeat_interval,
             request.is_loop.unwrap_or(false) as i32,
             request.loop_interval,
-            request.notify_on_trigger.unwrap_or(false) as i32,
-            request.notify_on_complete.unwrap_or(false) as i32,
-            request.notify_on_timeout.unwrap_or(false) as i32,
+            serde_json::to_string(&request.trigger_channels.unwrap_or_default()).unwrap(),
+            serde_json::to_string(&request.complete_channels.unwrap_or_default()).unwrap(),
+            serde_json::to_string(&request.timeout_channels.unwrap_or_default()).unwrap(),
             request.timeout_minutes,
+            request.is_critical.unwrap_or(false) as i32,
             now.to_rfc3339(),
             now.to_rfc3339(),
         ],
     )?;
 
     drop(conn);
-    get_by_uuid(&uuid).map(|opt| opt.expect("Just created reminder not found"))
+    get_by_uuid(&uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
 }
 
-pub fn update(request: UpdateReminderRequest) -> Result<Reminder, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn update(request: UpdateReminderRequest) -> AppResult<Reminder> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     // 构建动态更新语句
@@ -148,8 +223,10 @@ pub fn update(request: UpdateReminderRequest) -> Result<Reminder, rusqlite::Erro
     if let Some(ref remind_time) = request.remind_time {
         updates.push("remind_time = ?");
         params_vec.push(Box::new(remind_time.clone()));
-        // 当修改提醒时间时，清除上次触发时间，以便重新触发提醒
-        updates.push("last_triggered_at = NULL");
+    }
+    if let Some(ref remind_times) = request.remind_times {
+        updates.push("remind_times = ?");
+        params_vec.push(Box::new(serde_json::to_string(remind_times).unwrap()));
     }
     if let Some(ref remind_type) = request.remind_type {
         updates.push("remind_type = ?");
@@ -163,6 +240,110 @@ pub fn update(request: UpdateReminderRequest) -> Result<Reminder, rusqlite::Erro
         updates.push("monthdays = ?");
         params_vec.push(Box::new(serde_json::to_string(monthdays).unwrap()));
     }
+    if let Some(remind_date) = request.remind_date {
+        updates.push("remind_date = ?");
+        params_vec.push(Box::new(remind_date.format("%Y-%m-%d").to_string()));
+    }
+    if let Some(ref recurrence_rule) = request.recurrence_rule {
+        updates.push("recurrence_rule = ?");
+        params_vec.push(Box::new(recurrence_rule.clone()));
+    }
+    if let Some(interval_value) = request.interval_value {
+        updates.push("interval_value = ?");
+        params_vec.push(Box::new(interval_value));
+    }
+    if let Some(ref interval_unit) = request.interval_unit {
+        updates.push("interval_unit = ?");
+        params_vec.push(Box::new(interval_unit.as_str().to_string()));
+    }
+    if let Some(anchor_at) = request.anchor_at {
+        updates.push("anchor_at = ?");
+        params_vec.push(Box::new(anchor_at.to_rfc3339()));
+    }
+    if let Some(ref window_start) = request.window_start {
+        updates.push("window_start = ?");
+        params_vec.push(Box::new(window_start.clone()));
+    }
+    if let Some(ref window_end) = request.window_end {
+        updates.push("window_end = ?");
+        params_vec.push(Box::new(window_end.clone()));
+    }
+    if let Some(skip_holidays) = request.skip_holidays {
+        updates.push("skip_holidays = ?");
+        params_vec.push(Box::new(skip_holidays as i32));
+    }
+    if let Some(lunar_month) = request.lunar_month {
+        updates.push("lunar_month = ?");
+        params_vec.push(Box::new(lunar_month));
+    }
+    if let Some(lunar_day) = request.lunar_day {
+        updates.push("lunar_day = ?");
+        params_vec.push(Box::new(lunar_day));
+    }
+    if let Some(ref lunar_leap_policy) = request.lunar_leap_policy {
+        updates.push("lunar_leap_policy = ?");
+        params_vec.push(Box::new(lunar_leap_policy.as_str().to_string()));
+    }
+    if let Some(lunar_days_before) = request.lunar_days_before {
+        updates.push("lunar_days_before = ?");
+        params_vec.push(Box::new(lunar_days_before));
+    }
+    if let Some(yearly_month) = request.yearly_month {
+        updates.push("yearly_month = ?");
+        params_vec.push(Box::new(yearly_month));
+    }
+    if let Some(yearly_day) = request.yearly_day {
+        updates.push("yearly_day = ?");
+        params_vec.push(Box::new(yearly_day));
+    }
+    if let Some(ref leap_day_policy) = request.leap_day_policy {
+        updates.push("leap_day_policy = ?");
+        params_vec.push(Box::new(leap_day_policy.as_str().to_string()));
+    }
+    if let Some(ref monthly_mode) = request.monthly_mode {
+        updates.push("monthly_mode = ?");
+        params_vec.push(Box::new(monthly_mode.as_str().to_string()));
+    }
+    if let Some(month_week) = request.month_week {
+        updates.push("month_week = ?");
+        params_vec.push(Box::new(month_week));
+    }
+    if let Some(month_weekday) = request.month_weekday {
+        updates.push("month_weekday = ?");
+        params_vec.push(Box::new(month_weekday));
+    }
+    if let Some(days_before_end) = request.days_before_end {
+        updates.push("days_before_end = ?");
+        params_vec.push(Box::new(days_before_end));
+    }
+    if let Some(ref time_zone) = request.time_zone {
+        // 空字符串表示恢复为本机时区
+        updates.push("time_zone = ?");
+        params_vec.push(Box::new(Some(time_zone.clone()).filter(|tz| !tz.is_empty())));
+    }
+    if let Some(ref dst_policy) = request.dst_policy {
+        updates.push("dst_policy = ?");
+        params_vec.push(Box::new(dst_policy.as_str().to_string()));
+    }
+    if let Some(starts_on) = request.starts_on {
+        updates.push("starts_on = ?");
+        params_vec.push(Box::new(starts_on.map(|d| d.format("%Y-%m-%d").to_string())));
+    }
+    if let Some(ends_on) = request.ends_on {
+        updates.push("ends_on = ?");
+        params_vec.push(Box::new(ends_on.map(|d| d.format("%Y-%m-%d").to_string())));
+    }
+    if let Some(max_occurrences) = request.max_occurrences {
+        // 0 表示取消次数限制
+        updates.push("max_occurrences = ?");
+        params_vec.push(Box::new(Some(max_occurrences).filter(|n| *n > 0)));
+    }
+    // 当修改提醒时间或日期时，清除上次触发时间，以便重新触发提醒
+    if request.remind_time.is_some() || request.remind_times.is_some() || request.remind_date.is_some()
+        || request.time_zone.is_some()
+    {
+        updates.push("last_triggered_at = NULL");
+    }
     if let Some(is_enabled) = request.is_enabled {
         updates.push("is_enabled = ?");
         params_vec.push(Box::new(is_enabled as i32));
@@ -183,22 +364,26 @@ pub fn update(request: UpdateReminderRequest) -> Result<Reminder, rusqlite::Erro
         updates.push("loop_interval = ?");
         params_vec.push(Box::new(loop_interval));
     }
-    if let Some(notify_on_trigger) = request.notify_on_trigger {
-        updates.push("notify_on_trigger = ?");
-        params_vec.push(Box::new(notify_on_trigger as i32));
+    if let Some(ref trigger_channels) = request.trigger_channels {
+        updates.push("trigger_channels = ?");
+        params_vec.push(Box::new(serde_json::to_string(trigger_channels).unwrap()));
     }
-    if let Some(notify_on_complete) = request.notify_on_complete {
-        updates.push("notify_on_complete = ?");
-        params_vec.push(Box::new(notify_on_complete as i32));
+    if let Some(ref complete_channels) = request.complete_channels {
+        updates.push("complete_channels = ?");
+        params_vec.push(Box::new(serde_json::to_string(complete_channels).unwrap()));
     }
-    if let Some(notify_on_timeout) = request.notify_on_timeout {
-        updates.push("notify_on_timeout = ?");
-        params_vec.push(Box::new(notify_on_timeout as i32));
+    if let Some(ref timeout_channels) = request.timeout_channels {
+        updates.push("timeout_channels = ?");
+        params_vec.push(Box::new(serde_json::to_string(timeout_channels).unwrap()));
     }
     if let Some(timeout_minutes) = request.timeout_minutes {
         updates.push("timeout_minutes = ?");
         params_vec.push(Box::new(timeout_minutes));
     }
+    if let Some(is_critical) = request.is_critical {
+        updates.push("is_critical = ?");
+        params_vec.push(Box::new(is_critical as i32));
+    }
     if let Some(sort_order) = request.sort_order {
         updates.push("sort_order = ?");
         params_vec.push(Box::new(sort_order));
@@ -215,11 +400,11 @@ pub fn update(request: UpdateReminderRequest) -> Result<Reminder, rusqlite::Erro
     conn.execute(&sql, params_refs.as_slice())?;
 
     drop(conn);
-    get_by_uuid(&request.uuid).map(|opt| opt.expect("Updated reminder not found"))
+    get_by_uuid(&request.uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
 }
 
-pub fn soft_delete(uuid: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn soft_delete(uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     conn.execute(
@@ -230,8 +415,8 @@ pub fn soft_delete(uuid: &str) -> Result<(), rusqlite::Error> {
     Ok(())
 }
 
-pub fn toggle_enabled(uuid: &str) -> Result<Reminder, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn toggle_enabled(uuid: &str) -> AppResult<Reminder> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     conn.execute(
@@ -240,11 +425,36 @@ pub fn toggle_enabled(uuid: &str) -> Result<Reminder, rusqlite::Error> {
     )?;
 
     drop(conn);
-    get_by_uuid(uuid).map(|opt| opt.expect("Toggled reminder not found"))
+    get_by_uuid(uuid)?.ok_or(AppError::Storage(rusqlite::Error::QueryReturnedNoRows))
+}
+
+pub fn set_enabled(uuid: &str, enabled: bool) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
+    let now = Utc::now();
+
+    conn.execute(
+        "UPDATE reminders SET is_enabled = ?, updated_at = ?, sync_status = 'pending' WHERE uuid = ? AND deleted_at IS NULL",
+        params![enabled as i32, now.to_rfc3339(), uuid],
+    )?;
+
+    Ok(())
+}
+
+/// 设置暂停截止时间（None 表示恢复）
+pub fn set_paused_until(uuid: &str, paused_until: Option<DateTime<Utc>>) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
+    let now = Utc::now();
+
+    conn.execute(
+        "UPDATE reminders SET paused_until = ?, updated_at = ?, sync_status = 'pending' WHERE uuid = ? AND deleted_at IS NULL",
+        params![paused_until.map(|dt| dt.to_rfc3339()), now.to_rfc3339(), uuid],
+    )?;
+
+    Ok(())
 }
 
-pub fn update_sort_orders(uuids: &[String]) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn update_sort_orders(uuids: &[String]) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     for (index, uuid) in uuids.iter().enumerate() {
@@ -257,8 +467,8 @@ pub fn update_sort_orders(uuids: &[String]) -> Result<(), rusqlite::Error> {
     Ok(())
 }
 
-pub fn update_triggered_at(uuid: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn update_triggered_at(uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     conn.execute(
@@ -269,8 +479,8 @@ pub fn update_triggered_at(uuid: &str) -> Result<(), rusqlite::Error> {
     Ok(())
 }
 
-pub fn update_completed_at(uuid: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn update_completed_at(uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     conn.execute(
@@ -281,8 +491,8 @@ pub fn update_completed_at(uuid: &str) -> Result<(), rusqlite::Error> {
     Ok(())
 }
 
-pub fn get_enabled_reminders() -> Result<Vec<Reminder>, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_enabled_reminders() -> AppResult<Vec<Reminder>> {
+    let conn = get_connection()?.lock().unwrap();
     let mut stmt = conn.prepare(
         "SELECT * FROM reminders WHERE is_enabled = 1 AND deleted_at IS NULL ORDER BY remind_time ASC"
     )?;
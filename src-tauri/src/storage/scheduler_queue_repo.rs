@@ -0,0 +1,68 @@
+use rusqlite::{params, Row};
+use chrono::{DateTime, Utc};
+use crate::models::queue_item::{QueueItem, QueueType};
+use crate::error::AppResult;
+use crate::storage::database::get_connection;
+
+fn row_to_item(row: &Row) -> rusqlite::Result<QueueItem> {
+    let queue_type_str: String = row.get("queue_type")?;
+
+    Ok(QueueItem {
+        queue_type: QueueType::from_str(&queue_type_str),
+        reminder_uuid: row.get("reminder_uuid")?,
+        due_at: DateTime::parse_from_rfc3339(&row.get::<_, String>("due_at")?)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        original_triggered_at: DateTime::parse_from_rfc3339(&row.get::<_, String>("original_triggered_at")?)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        timeout_minutes: row.get("timeout_minutes")?,
+    })
+}
+
+/// 写入队列项（同一提醒在同一队列中只保留一项）
+pub fn upsert(item: &QueueItem) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
+    let now = Utc::now();
+
+    conn.execute(
+        "INSERT INTO scheduler_queue (
+            queue_type, reminder_uuid, due_at, original_triggered_at, timeout_minutes, created_at
+        ) VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(queue_type, reminder_uuid) DO UPDATE SET
+        due_at = excluded.due_at,
+        original_triggered_at = excluded.original_triggered_at,
+        timeout_minutes = excluded.timeout_minutes",
+        params![
+            item.queue_type.as_str(),
+            item.reminder_uuid,
+            item.due_at.to_rfc3339(),
+            item.original_triggered_at.to_rfc3339(),
+            item.timeout_minutes,
+            now.to_rfc3339(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// 移除队列项
+pub fn remove(queue_type: QueueType, reminder_uuid: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
+    conn.execute(
+        "DELETE FROM scheduler_queue WHERE queue_type = ? AND reminder_uuid = ?",
+        params![queue_type.as_str(), reminder_uuid],
+    )?;
+    Ok(())
+}
+
+/// 获取所有队列项（按到期时间排序）
+pub fn get_all() -> AppResult<Vec<QueueItem>> {
+    let conn = get_connection()?.lock().unwrap();
+    let mut stmt = conn.prepare("SELECT * FROM scheduler_queue ORDER BY due_at ASC")?;
+
+    let items = stmt.query_map([], |row| row_to_item(row))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(items)
+}
@@ -1,10 +1,11 @@
 use rusqlite::params;
 use chrono::Utc;
 use crate::models::settings::AppSettings;
+use crate::error::AppResult;
 use crate::storage::database::get_connection;
 
-pub fn get_all() -> Result<AppSettings, rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+pub fn get_all() -> AppResult<AppSettings> {
+    let conn = get_connection()?.lock().unwrap();
     let mut settings = AppSettings::default();
 
     let mut stmt = conn.prepare("SELECT key, value FROM settings")?;
@@ -30,6 +31,56 @@ pub fn get_all() -> Result<AppSettings, rusqlite::Error> {
             "window_position" => {
                 settings.window_position = serde_json::from_str(&value).ok();
             }
+            "missed_reminder_mode" => {
+                settings.missed_reminder_mode = serde_json::from_str(&value).unwrap_or_default();
+            }
+            "dnd_enabled" => {
+                settings.dnd_enabled = serde_json::from_str(&value).unwrap_or(false);
+            }
+            "quiet_hours" => {
+                settings.quiet_hours = serde_json::from_str(&value).unwrap_or_default();
+            }
+            "dnd_allow_serverchan" => {
+                settings.dnd_allow_serverchan = serde_json::from_str(&value).unwrap_or(true);
+            }
+            "defer_popups_when_presenting" => {
+                settings.defer_popups_when_presenting = serde_json::from_str(&value).unwrap_or(true);
+            }
+            "max_popup_defer_minutes" => {
+                settings.max_popup_defer_minutes = serde_json::from_str(&value).unwrap_or(30);
+            }
+            "backup_enabled" => {
+                settings.backup_enabled = serde_json::from_str(&value).unwrap_or(true);
+            }
+            "backup_dir" => {
+                settings.backup_dir = serde_json::from_str(&value).ok();
+            }
+            "backup_interval_hours" => {
+                settings.backup_interval_hours = serde_json::from_str(&value).unwrap_or(24);
+            }
+            "backup_retention" => {
+                settings.backup_retention = serde_json::from_str(&value).unwrap_or(7);
+            }
+            "smtp" => {
+                settings.smtp = serde_json::from_str(&value).ok().flatten();
+            }
+            "digest_enabled" => {
+                settings.digest_enabled = serde_json::from_str(&value).unwrap_or(false);
+            }
+            "digest_frequency" => {
+                settings.digest_frequency = serde_json::from_str(&value).unwrap_or_default();
+            }
+            "digest_time" => {
+                if let Ok(time) = serde_json::from_str(&value) {
+                    settings.digest_time = time;
+                }
+            }
+            "digest_weekday" => {
+                settings.digest_weekday = serde_json::from_str(&value).unwrap_or(1);
+            }
+            "digest_recipients" => {
+                settings.digest_recipients = serde_json::from_str(&value).unwrap_or_default();
+            }
             _ => {}
         }
     }
@@ -37,8 +88,16 @@ pub fn get_all() -> Result<AppSettings, rusqlite::Error> {
     Ok(settings)
 }
 
-pub fn set(key: &str, value: &str) -> Result<(), rusqlite::Error> {
-    let conn = get_connection().lock().unwrap();
+/// 读取单个设置项的原始值
+pub fn get(key: &str) -> AppResult<Option<String>> {
+    let conn = get_connection()?.lock().unwrap();
+    let mut stmt = conn.prepare("SELECT value FROM settings WHERE key = ?")?;
+    let mut rows = stmt.query_map(params![key], |row| row.get(0))?;
+    Ok(rows.next().transpose()?)
+}
+
+pub fn set(key: &str, value: &str) -> AppResult<()> {
+    let conn = get_connection()?.lock().unwrap();
     let now = Utc::now();
 
     conn.execute(
@@ -55,7 +114,7 @@ pub fn set(key: &str, value: &str) -> Result<(), rusqlite::Error> {
     Ok(())
 }
 
-pub fn update_all(settings: &AppSettings) -> Result<(), rusqlite::Error> {
+pub fn update_all(settings: &AppSettings) -> AppResult<()> {
     if let Some(ref domain) = settings.serverchan_domain {
         set("serverchan_domain", &serde_json::to_string(domain).unwrap())?;
     }
@@ -67,6 +126,22 @@ pub fn update_all(settings: &AppSettings) -> Result<(), rusqlite::Error> {
     if let Some(ref pos) = settings.window_position {
         set("window_position", &serde_json::to_string(pos).unwrap())?;
     }
+    set("missed_reminder_mode", &serde_json::to_string(&settings.missed_reminder_mode).unwrap())?;
+    set("dnd_enabled", &serde_json::to_string(&settings.dnd_enabled).unwrap())?;
+    set("quiet_hours", &serde_json::to_string(&settings.quiet_hours).unwrap())?;
+    set("dnd_allow_serverchan", &serde_json::to_string(&settings.dnd_allow_serverchan).unwrap())?;
+    set("defer_popups_when_presenting", &serde_json::to_string(&settings.defer_popups_when_presenting).unwrap())?;
+    set("max_popup_defer_minutes", &serde_json::to_string(&settings.max_popup_defer_minutes).unwrap())?;
+    set("backup_enabled", &serde_json::to_string(&settings.backup_enabled).unwrap())?;
+    set("backup_dir", &serde_json::to_string(&settings.backup_dir).unwrap())?;
+    set("backup_interval_hours", &serde_json::to_string(&settings.backup_interval_hours).unwrap())?;
+    set("backup_retention", &serde_json::to_string(&settings.backup_retention).unwrap())?;
+    set("smtp", &serde_json::to_string(&settings.smtp).unwrap())?;
+    set("digest_enabled", &serde_json::to_string(&settings.digest_enabled).unwrap())?;
+    set("digest_frequency", &serde_json::to_string(&settings.digest_frequency).unwrap())?;
+    set("digest_time", &serde_json::to_string(&settings.digest_time).unwrap())?;
+    set("digest_weekday", &serde_json::to_string(&settings.digest_weekday).unwrap())?;
+    set("digest_recipients", &serde_json::to_string(&settings.digest_recipients).unwrap())?;
 
     Ok(())
 }
@@ -0,0 +1,251 @@
+use chrono::{Duration, NaiveDate};
+
+/// 农历数据表（1900-2100 年），每年一个编码：
+/// - 低 4 位：闰月月份，0 表示无闰月
+/// - 第 4-15 位：正月到十二月的大小月，对应位为 1 表示 30 天，否则 29 天
+/// - 第 16 位：闰月为大月（30 天）
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2, // 1900-1909
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977, // 1910-1919
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970, // 1920-1929
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950, // 1930-1939
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557, // 1940-1949
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0, // 1950-1959
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0, // 1960-1969
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6, // 1970-1979
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570, // 1980-1989
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0, // 1990-1999
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5, // 2000-2009
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930, // 2010-2019
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530, // 2020-2029
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45, // 2030-2039
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0, // 2040-2049
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0, // 2050-2059
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4, // 2060-2069
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0, // 2070-2079
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160, // 2080-2089
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252, // 2090-2099
+    0x0d520, // 2100
+];
+
+const MIN_YEAR: i32 = 1900;
+const MAX_YEAR: i32 = 2100;
+
+/// 农历日期
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LunarDate {
+    pub year: i32,
+    /// 月份 1-12
+    pub month: u8,
+    /// 日期 1-30
+    pub day: u8,
+    /// 是否为闰月
+    pub is_leap: bool,
+}
+
+/// 农历 1900 年正月初一对应的公历日期
+fn base_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1900, 1, 31).unwrap()
+}
+
+fn info(year: i32) -> Option<u32> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return None;
+    }
+    Some(LUNAR_INFO[(year - MIN_YEAR) as usize])
+}
+
+/// 农历年的闰月月份（无闰月返回 None）
+pub fn leap_month(year: i32) -> Option<u8> {
+    info(year)
+        .map(|info| (info & 0xf) as u8)
+        .filter(|month| *month != 0)
+}
+
+/// 农历月的天数（29 或 30），闰月需指定 `is_leap`
+pub fn month_days(year: i32, month: u8, is_leap: bool) -> Option<u8> {
+    let info = info(year)?;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    if is_leap {
+        if leap_month(year) != Some(month) {
+            return None;
+        }
+        return Some(if info & 0x10000 != 0 { 30 } else { 29 });
+    }
+    Some(if info & (0x10000 >> month) != 0 { 30 } else { 29 })
+}
+
+/// 农历年的总天数
+fn year_days(year: i32) -> Option<i64> {
+    let mut days = 0;
+    for month in 1..=12 {
+        days += month_days(year, month, false)? as i64;
+    }
+    if let Some(leap) = leap_month(year) {
+        days += month_days(year, leap, true)? as i64;
+    }
+    Some(days)
+}
+
+/// 按顺序列出农历年的各月（闰月紧跟在同名月之后）
+fn months_of(year: i32) -> Vec<(u8, bool)> {
+    let leap = leap_month(year);
+    let mut months = Vec::with_capacity(13);
+    for month in 1..=12 {
+        months.push((month, false));
+        if leap == Some(month) {
+            months.push((month, true));
+        }
+    }
+    months
+}
+
+/// 公历转农历（支持 1900-01-31 至 2100 年底）
+pub fn from_solar(date: NaiveDate) -> Option<LunarDate> {
+    let mut offset = (date - base_date()).num_days();
+    if offset < 0 {
+        return None;
+    }
+
+    let mut year = MIN_YEAR;
+    loop {
+        let days = year_days(year)?;
+        if offset < days {
+            break;
+        }
+        offset -= days;
+        year += 1;
+    }
+
+    for (month, is_leap) in months_of(year) {
+        let days = month_days(year, month, is_leap)? as i64;
+        if offset < days {
+            return Some(LunarDate { year, month, day: offset as u8 + 1, is_leap });
+        }
+        offset -= days;
+    }
+
+    None
+}
+
+/// 农历转公历，日期超出当月天数时返回 None
+pub fn to_solar(lunar: LunarDate) -> Option<NaiveDate> {
+    if lunar.day == 0 || lunar.day > month_days(lunar.year, lunar.month, lunar.is_leap)? {
+        return None;
+    }
+
+    let mut offset = 0;
+    for year in MIN_YEAR..lunar.year {
+        offset += year_days(year)?;
+    }
+    for (month, is_leap) in months_of(lunar.year) {
+        if month == lunar.month && is_leap == lunar.is_leap {
+            break;
+        }
+        offset += month_days(lunar.year, month, is_leap)? as i64;
+    }
+    offset += lunar.day as i64 - 1;
+
+    base_date().checked_add_signed(Duration::days(offset))
+}
+
+const MONTH_NAMES: [&str; 12] = ["正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊"];
+const DAY_PREFIXES: [&str; 4] = ["初", "十", "廿", "三"];
+const DIGITS: [&str; 10] = ["十", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+impl LunarDate {
+    /// 中文表示，例如 "闰六月初一"、"腊月廿三"
+    pub fn to_chinese(&self) -> String {
+        let month = format!(
+            "{}{}月",
+            if self.is_leap { "闰" } else { "" },
+            MONTH_NAMES[(self.month - 1) as usize]
+        );
+        let day = match self.day {
+            10 => "初十".to_string(),
+            20 => "二十".to_string(),
+            30 => "三十".to_string(),
+            d => format!("{}{}", DAY_PREFIXES[(d / 10) as usize], DIGITS[(d % 10) as usize]),
+        };
+        format!("{}{}", month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solar(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn lunar(year: i32, month: u8, day: u8, is_leap: bool) -> LunarDate {
+        LunarDate { year, month, day, is_leap }
+    }
+
+    #[test]
+    fn converts_known_dates_both_ways() {
+        let cases = [
+            (solar(1900, 1, 31), lunar(1900, 1, 1, false)),
+            (solar(2000, 2, 5), lunar(2000, 1, 1, false)),
+            (solar(2024, 9, 17), lunar(2024, 8, 15, false)),
+            (solar(2026, 2, 10), lunar(2025, 12, 23, false)),
+            (solar(2026, 2, 17), lunar(2026, 1, 1, false)),
+            (solar(2100, 2, 9), lunar(2100, 1, 1, false)),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(from_solar(date), Some(expected), "{}", date);
+            assert_eq!(to_solar(expected), Some(date), "{:?}", expected);
+        }
+    }
+
+    #[test]
+    fn converts_leap_months() {
+        // 2023 年闰二月
+        assert_eq!(leap_month(2023), Some(2));
+        assert_eq!(from_solar(solar(2023, 2, 20)), Some(lunar(2023, 2, 1, false)));
+        assert_eq!(from_solar(solar(2023, 3, 22)), Some(lunar(2023, 2, 1, true)));
+        assert_eq!(from_solar(solar(2023, 4, 20)), Some(lunar(2023, 3, 1, false)));
+        assert_eq!(to_solar(lunar(2023, 2, 1, true)), Some(solar(2023, 3, 22)));
+
+        // 2033 年闰冬月（闰十一月）
+        assert_eq!(leap_month(2033), Some(11));
+        assert_eq!(from_solar(solar(2033, 11, 22)), Some(lunar(2033, 11, 1, false)));
+        assert_eq!(from_solar(solar(2033, 12, 22)), Some(lunar(2033, 11, 1, true)));
+        assert_eq!(to_solar(lunar(2033, 11, 1, true)), Some(solar(2033, 12, 22)));
+        assert_eq!(lunar(2033, 11, 1, true).to_chinese(), "闰冬月初一");
+
+        // 当年没有的闰月
+        assert_eq!(month_days(2024, 2, true), None);
+        assert_eq!(to_solar(lunar(2024, 2, 1, true)), None);
+    }
+
+    #[test]
+    fn rejects_days_past_month_end_and_range() {
+        let days = month_days(2025, 12, false).unwrap();
+        assert_eq!(to_solar(lunar(2025, 12, days + 1, false)), None);
+        assert_eq!(to_solar(lunar(2025, 12, 0, false)), None);
+        assert_eq!(from_solar(solar(1900, 1, 30)), None);
+        assert_eq!(leap_month(2101), None);
+    }
+
+    #[test]
+    fn round_trips_every_day_of_a_leap_year() {
+        let mut date = solar(2033, 1, 1);
+        while date < solar(2035, 1, 1) {
+            let converted = from_solar(date).unwrap();
+            assert_eq!(to_solar(converted), Some(date));
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn formats_chinese_names() {
+        assert_eq!(lunar(2025, 6, 1, true).to_chinese(), "闰六月初一");
+        assert_eq!(lunar(2025, 12, 23, false).to_chinese(), "腊月廿三");
+        assert_eq!(lunar(2025, 1, 10, false).to_chinese(), "正月初十");
+        assert_eq!(lunar(2025, 8, 30, false).to_chinese(), "八月三十");
+    }
+}
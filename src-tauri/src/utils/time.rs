@@ -1,8 +1,10 @@
-use chrono::{Local, NaiveTime, Timelike};
+use chrono::{Duration, Local, NaiveTime, Timelike};
 
-/// 解析时间字符串 "HH:MM"
+/// 解析时间字符串 "HH:MM"（也接受精确到秒的 "HH:MM:SS"）
 pub fn parse_time(time_str: &str) -> Option<NaiveTime> {
-    NaiveTime::parse_from_str(time_str, "%H:%M").ok()
+    NaiveTime::parse_from_str(time_str, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(time_str, "%H:%M"))
+        .ok()
 }
 
 /// 获取当前时间的 "HH:MM" 格式
@@ -27,3 +29,21 @@ pub fn minutes_until(time_str: &str) -> Option<i64> {
         Some(diff + 24 * 60)
     }
 }
+
+/// 格式化剩余时间，例如 "2小时13分钟"
+pub fn format_remaining(duration: Duration) -> String {
+    let total_minutes = duration.num_minutes().max(0);
+    let days = total_minutes / (24 * 60);
+    let hours = total_minutes / 60 % 24;
+    let minutes = total_minutes % 60;
+
+    if days > 0 {
+        format!("{}天{}小时", days, hours)
+    } else if hours > 0 {
+        format!("{}小时{}分钟", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}分钟", minutes)
+    } else {
+        "不到1分钟".to_string()
+    }
+}